@@ -0,0 +1,264 @@
+//! The arguments of the macros
+
+use crate::types::Primitive;
+use syn::parse::{Parse, ParseStream};
+use syn::Result;
+
+// proc macro inputs argument
+pub(crate) struct Args {
+    // the name of the Simulink model
+    pub(crate) control: syn::Ident,
+    // the path to the Simulink C model header file
+    pub(crate) header: Option<syn::LitStr>,
+    // the Simulink C model directory
+    pub(crate) dir: Option<syn::LitStr>,
+    // the name of the Simulink model whose header is looked for in the model directory
+    pub(crate) model_name: Option<syn::LitStr>,
+    // derive `PartialOrd` and `Ord` for the Simulink enumerations
+    pub(crate) enum_ord: bool,
+    // write a step method guarded against unwinding panics
+    pub(crate) catch_unwind: bool,
+    // track the outputs range
+    pub(crate) output_range: bool,
+    // write the inputs setters with a dead-band
+    pub(crate) deadband: bool,
+    // write the interior mutability wrapper
+    pub(crate) interior_mutability: bool,
+    // the path to the initial conditions file
+    pub(crate) init: Option<syn::LitStr>,
+    // write the inputs, outputs and states in cache line aligned storage
+    pub(crate) cache_aligned: bool,
+    // make the states fields public and write the states accessors
+    pub(crate) expose_states: bool,
+    // write the bindings include and the Simulink enumerations
+    pub(crate) bindings: bool,
+    // the module of the bindings written elsewhere, e.g. `crate::ffi`
+    pub(crate) bindings_path: Option<syn::Path>,
+    // the wrapper visibility
+    pub(crate) vis: Option<syn::Visibility>,
+    // the wrapper name
+    pub(crate) name: Option<syn::Ident>,
+    // the Rust type of `real_T`, detected from the headers if not given
+    pub(crate) precision: Option<Primitive>,
+    // the states variables left out of the snapshot
+    pub(crate) skip_states: Vec<syn::Ident>,
+    // the headers file name suffixes left out of the model header discovery
+    pub(crate) exclude: Option<Vec<String>>,
+    // the module of the generated items
+    pub(crate) module: Option<syn::Ident>,
+}
+impl Parse for Args {
+    // inputs argument parser
+    fn parse(input: ParseStream) -> Result<Self> {
+        let model = input.parse()?;
+        // optional header: `import!(Control, "sys/model.h")`
+        let header = if input.peek(syn::Token![,]) && input.peek2(syn::LitStr) {
+            input.parse::<syn::Token![,]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let mut args = Self {
+            control: model,
+            header,
+            dir: None,
+            model_name: None,
+            enum_ord: false,
+            catch_unwind: false,
+            output_range: false,
+            deadband: false,
+            interior_mutability: false,
+            init: None,
+            cache_aligned: false,
+            expose_states: false,
+            bindings: true,
+            bindings_path: None,
+            vis: None,
+            name: None,
+            precision: None,
+            skip_states: vec![],
+            exclude: None,
+            module: None,
+        };
+        // optional flags: `import!(Control, enum_ord, catch_unwind, output_range, deadband, interior_mutability, cache_aligned, expose_states)`
+        // and key-value pairs: `import!(Control, init = "ic.csv", bindings = false, bindings = crate::ffi, vis = pub(crate), name = Wrapper, dir = "codegen", model = "attitude_control", precision = f32, skip_states = [scratch], exclude = ["_capi.h"], module = controller)`
+        while input.parse::<Option<syn::Token![,]>>()?.is_some() {
+            if input.is_empty() {
+                break;
+            }
+            let flag: syn::Ident = input.parse()?;
+            match flag.to_string().as_str() {
+                "init" => {
+                    input.parse::<syn::Token![=]>()?;
+                    args.init = Some(input.parse()?);
+                }
+                "bindings" => {
+                    input.parse::<syn::Token![=]>()?;
+                    if input.peek(syn::LitBool) {
+                        args.bindings = input.parse::<syn::LitBool>()?.value;
+                    } else {
+                        // the bindings are in another module, imported by the wrapper module
+                        args.bindings = false;
+                        args.bindings_path = Some(input.parse()?);
+                    }
+                }
+                "dir" => {
+                    input.parse::<syn::Token![=]>()?;
+                    args.dir = Some(input.parse()?);
+                }
+                "model" => {
+                    input.parse::<syn::Token![=]>()?;
+                    if args.header.is_some() {
+                        return Err(syn::Error::new(
+                            flag.span(),
+                            "the `model` argument and the header argument both select the model \
+                             header, give only one of them",
+                        ));
+                    }
+                    args.model_name = Some(input.parse()?);
+                }
+                "vis" => {
+                    input.parse::<syn::Token![=]>()?;
+                    args.vis = Some(input.parse()?);
+                }
+                "name" => {
+                    input.parse::<syn::Token![=]>()?;
+                    args.name = Some(input.parse()?);
+                }
+                "module" => {
+                    input.parse::<syn::Token![=]>()?;
+                    args.module = Some(input.parse()?);
+                }
+                "precision" => {
+                    input.parse::<syn::Token![=]>()?;
+                    let precision: syn::Ident = input.parse()?;
+                    args.precision = Some(match precision.to_string().as_str() {
+                        "f64" => Primitive::F64,
+                        "f32" => Primitive::F32,
+                        _ => {
+                            return Err(syn::Error::new(
+                                precision.span(),
+                                format!(
+                                    "unknown precision `{}`, expected `f64` or `f32`",
+                                    precision
+                                ),
+                            ))
+                        }
+                    });
+                }
+                "skip_states" => {
+                    input.parse::<syn::Token![=]>()?;
+                    let content;
+                    syn::bracketed!(content in input);
+                    args.skip_states = content
+                        .parse_terminated::<_, syn::Token![,]>(syn::Ident::parse)?
+                        .into_iter()
+                        .collect();
+                }
+                "exclude" => {
+                    input.parse::<syn::Token![=]>()?;
+                    let content;
+                    syn::bracketed!(content in input);
+                    args.exclude = Some(
+                        content
+                            .parse_terminated::<_, syn::Token![,]>(<syn::LitStr as Parse>::parse)?
+                            .into_iter()
+                            .map(|suffix| suffix.value())
+                            .collect(),
+                    );
+                }
+                "enum_ord" => args.enum_ord = true,
+                "catch_unwind" => args.catch_unwind = true,
+                "output_range" => args.output_range = true,
+                "deadband" => args.deadband = true,
+                "interior_mutability" => args.interior_mutability = true,
+                "cache_aligned" => args.cache_aligned = true,
+                "expose_states" => args.expose_states = true,
+                _ => {
+                    return Err(syn::Error::new(
+                        flag.span(),
+                        format!("unknown argument `{}`", flag),
+                    ))
+                }
+            }
+        }
+        Ok(args)
+    }
+}
+
+// import_bindings proc macro inputs argument
+pub(crate) struct Bindings {
+    // derive `PartialOrd` and `Ord` for the Simulink enumerations
+    pub(crate) enum_ord: bool,
+}
+impl Parse for Bindings {
+    // inputs argument parser
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut enum_ord = false;
+        if !input.is_empty() {
+            let flag: syn::Ident = input.parse()?;
+            if flag != "enum_ord" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    format!("unknown argument `{}`", flag),
+                ));
+            }
+            enum_ord = true;
+            input.parse::<Option<syn::Token![,]>>()?;
+        }
+        Ok(Self { enum_ord })
+    }
+}
+
+// feedback proc macro inputs argument
+pub(crate) struct Feedback {
+    // the name of the Simulink plant model
+    pub(crate) plant: syn::Ident,
+    // the name of the Simulink controller model
+    pub(crate) controller: syn::Ident,
+    // the Simulink C models directory
+    pub(crate) dir: Option<syn::LitStr>,
+}
+impl Parse for Feedback {
+    // inputs argument parser
+    fn parse(input: ParseStream) -> Result<Self> {
+        let plant = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let controller = input.parse()?;
+        let mut dir = None;
+        // optional key-value pair: `feedback!(Plant, Controller, dir = "codegen")`
+        while input.parse::<Option<syn::Token![,]>>()?.is_some() {
+            if input.is_empty() {
+                break;
+            }
+            let flag: syn::Ident = input.parse()?;
+            match flag.to_string().as_str() {
+                "dir" => {
+                    input.parse::<syn::Token![=]>()?;
+                    dir = Some(input.parse()?);
+                }
+                "header" | "model" => {
+                    return Err(syn::Error::new(
+                        flag.span(),
+                        format!(
+                            "the `{}` argument isn't supported by `feedback!`, the models headers \
+                             are `<dir>/<Plant>.h` and `<dir>/<Controller>.h`",
+                            flag
+                        ),
+                    ))
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        flag.span(),
+                        format!("unknown argument `{}`", flag),
+                    ))
+                }
+            }
+        }
+        Ok(Self {
+            plant,
+            controller,
+            dir,
+        })
+    }
+}
@@ -0,0 +1,50 @@
+//! The bindgen bindings and the `import_bindings!` macro
+
+use crate::args::Bindings;
+use crate::enums::{parse_enums, Enumeration};
+use crate::parse::{headers, sys_dir};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::Path;
+
+// The bindgen bindings include and the Simulink enumerations of the headers in `sys`
+pub(crate) fn quote_bindings(sys: &Path, enum_ord: bool) -> proc_macro2::TokenStream {
+    let mut enums: Vec<Enumeration> = vec![];
+    for file_name in headers(sys) {
+        for enumeration in parse_enums(&file_name) {
+            if !enums.iter().any(|e| e.name == enumeration.name) {
+                enums.push(enumeration);
+            }
+        }
+    }
+    for enumeration in &enums {
+        diagnostic!("| enum {}:", enumeration.name);
+        for (name, value) in &enumeration.members {
+            diagnostic!("|  - {:<22}: {:>5}", name, value);
+        }
+    }
+    let enums = enums
+        .iter()
+        .fold(proc_macro2::TokenStream::default(), |t, e| {
+            let e = e.quote(enum_ord);
+            quote! {
+                #t
+                #e
+            }
+        });
+    quote! {
+        include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+        #enums
+    }
+}
+
+/// The `import_bindings!` macro expansion
+pub fn import_bindings(input: TokenStream) -> TokenStream {
+    let Bindings { enum_ord } = match syn::parse2(input) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error(),
+    };
+    let sys = sys_dir(None);
+    quote_bindings(&sys, enum_ord)
+}
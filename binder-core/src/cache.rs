@@ -0,0 +1,229 @@
+//! The cache of the parsed models
+
+use crate::io::{List, IO};
+use crate::model::Model;
+use crate::types::Primitive;
+use proc_macro2::{Ident, Span};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use std::time::UNIX_EPOCH;
+
+impl Model {
+    // Parse the Simulink C header file `file_name` with the C types aliases, the checksum
+    // and the sample time of the headers and C files of the `sys` directory
+    //
+    // The parsed model is cached in the process and in `<OUT_DIR>/<header>_model.cache`,
+    // both invalidated when the modification time of the header or the crate version change.
+    // `real_T` is the `precision` type if given
+    pub(crate) fn load(
+        file_name: &Path,
+        sys: &Path,
+        precision: Option<Primitive>,
+        out_dir: Option<&Path>,
+    ) -> std::result::Result<Self, String> {
+        // the cache contents by key, the identifiers can't outlive a macro call
+        static MODELS: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+        let Some(key) = Self::cache_key(file_name, sys, precision) else {
+            return Self::load_uncached(file_name, sys, precision);
+        };
+        let models = MODELS.get_or_init(Default::default);
+        if let Some(model) = models
+            .lock()
+            .unwrap()
+            .get(&key)
+            .and_then(|contents| Self::from_cache(&key, contents))
+        {
+            return Ok(model);
+        }
+        let cache = out_dir.map(|out_dir| {
+            let stem = file_name.file_stem().unwrap_or_default().to_string_lossy();
+            out_dir.join(format!("{}_model.cache", stem))
+        });
+        let cached = cache
+            .as_ref()
+            .and_then(|cache| fs::read_to_string(cache).ok())
+            .and_then(|contents| Self::from_cache(&key, &contents).map(|model| (model, contents)));
+        let (model, contents) = match cached {
+            Some(cached) => cached,
+            None => {
+                let model = Self::load_uncached(file_name, sys, precision)?;
+                let contents = model.to_cache(&key);
+                if let Some(cache) = &cache {
+                    if let Err(e) = fs::write(cache, &contents) {
+                        diagnostic!("| failed to write {:?}: {}", cache, e);
+                    }
+                }
+                (model, contents)
+            }
+        };
+        models.lock().unwrap().insert(key, contents);
+        Ok(model)
+    }
+    // Cache key of the model: the crate version, the header and `sys` paths, the precision
+    // and the modification time of the header
+    fn cache_key(file_name: &Path, sys: &Path, precision: Option<Primitive>) -> Option<String> {
+        let modified = fs::metadata(file_name).and_then(|metadata| metadata.modified());
+        let mtime = modified.ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(format!(
+            "{}\t{}\t{}\t{:?}\t{}",
+            env!("CARGO_PKG_VERSION"),
+            file_name.display(),
+            sys.display(),
+            precision,
+            mtime.as_nanos()
+        ))
+    }
+    // Cache file contents, one line per field, variable and unparsed member declaration
+    fn to_cache(&self, key: &str) -> String {
+        let mut lines = vec![
+            format!("key\t{}", key),
+            format!("name\t{}", self.name),
+            format!("precision\t{:?}", self.precision),
+        ];
+        for (field, value) in [
+            ("version", &self.version),
+            ("coder_version", &self.coder_version),
+            ("codegen_date", &self.codegen_date),
+            ("solver", &self.solver),
+        ] {
+            if let Some(value) = value {
+                lines.push(format!("{}\t{}", field, value));
+            }
+        }
+        if let Some(checksum) = self.checksum {
+            let words: Vec<_> = checksum.iter().map(|word| word.to_string()).collect();
+            lines.push(format!("checksum\t{}", words.join(" ")));
+        }
+        if let Some(sample_time) = self.sample_time {
+            lines.push(format!("sample_time\t{}", sample_time));
+        }
+        if let Some(values) = &self.params_init {
+            let values: Vec<_> = values.iter().map(|value| value.to_string()).collect();
+            lines.push(format!("params_init\t{}", values.join(" ")));
+        }
+        let lists = [
+            ("inputs", self.inputs.as_ref()),
+            ("outputs", self.outputs.as_ref()),
+            ("states", self.states.as_ref()),
+            ("continuous", self.continuous.as_ref()),
+            ("zero_crossings", self.zero_crossings.as_ref()),
+            ("params", Some(&self.params)),
+        ];
+        for (place, list) in lists {
+            let Some(List(variables, pointers, unparsed)) = list else {
+                continue;
+            };
+            lines.push(format!("list\t{}\t{}", place, pointers));
+            for io in variables {
+                let size: Vec<_> = io.size.iter().map(|n| n.to_string()).collect();
+                lines.push(format!(
+                    "io\t{}\t{}\t{:?}\t{}\t{}",
+                    io.name,
+                    io.ctype,
+                    io.ty,
+                    size.join(" "),
+                    io.structs.join(" ")
+                ));
+            }
+            for line in unparsed {
+                lines.push(format!("unparsed\t{}", line));
+            }
+        }
+        for member in &self.unrecognized {
+            lines.push(format!("unrecognized\t{}", member));
+        }
+        lines.push(String::new());
+        lines.join("\n")
+    }
+    // Model from the cache file contents, `None` if the cache key doesn't match `key`
+    fn from_cache(key: &str, contents: &str) -> Option<Self> {
+        let mut lines = contents.lines();
+        if lines.next()?.strip_prefix("key\t")? != key {
+            return None;
+        }
+        let mut model = Self {
+            name: Ident::new("model", Span::call_site()),
+            version: None,
+            coder_version: None,
+            codegen_date: None,
+            solver: None,
+            inputs: None,
+            outputs: None,
+            states: None,
+            continuous: None,
+            zero_crossings: None,
+            params: List::default(),
+            params_init: None,
+            checksum: None,
+            sample_time: None,
+            precision: Primitive::F64,
+            unrecognized: vec![],
+        };
+        let mut list: Option<&mut List> = None;
+        for line in lines {
+            let (field, value) = line.split_once('\t')?;
+            match field {
+                "name" => model.name = syn::parse_str(value).ok()?,
+                "precision" => model.precision = Primitive::from_debug(value)?,
+                "version" => model.version = Some(value.to_string()),
+                "coder_version" => model.coder_version = Some(value.to_string()),
+                "codegen_date" => model.codegen_date = Some(value.to_string()),
+                "solver" => model.solver = Some(value.to_string()),
+                "checksum" => {
+                    let words: Vec<u32> = value
+                        .split(' ')
+                        .map(|word| word.parse().ok())
+                        .collect::<Option<_>>()?;
+                    model.checksum = Some(words.try_into().ok()?);
+                }
+                "sample_time" => model.sample_time = Some(value.parse().ok()?),
+                "params_init" => {
+                    model.params_init = Some(
+                        value
+                            .split_whitespace()
+                            .map(|value| value.parse().ok())
+                            .collect::<Option<_>>()?,
+                    )
+                }
+                "list" => {
+                    let (place, pointers) = value.split_once('\t')?;
+                    let new = List(vec![], pointers.parse().ok()?, vec![]);
+                    list = Some(match place {
+                        "inputs" => model.inputs.insert(new),
+                        "outputs" => model.outputs.insert(new),
+                        "states" => model.states.insert(new),
+                        "continuous" => model.continuous.insert(new),
+                        "zero_crossings" => model.zero_crossings.insert(new),
+                        "params" => {
+                            model.params = new;
+                            &mut model.params
+                        }
+                        _ => return None,
+                    });
+                }
+                "io" => {
+                    let fields: Vec<_> = value.split('\t').collect();
+                    let [name, ctype, ty, size, structs] = fields[..] else {
+                        return None;
+                    };
+                    list.as_mut()?.0.push(IO {
+                        name: name.to_string(),
+                        size: size
+                            .split_whitespace()
+                            .map(|n| n.parse().ok())
+                            .collect::<Option<_>>()?,
+                        ctype: ctype.to_string(),
+                        ty: Primitive::from_debug(ty)?,
+                        structs: structs.split_whitespace().map(String::from).collect(),
+                    });
+                }
+                "unparsed" => list.as_mut()?.2.push(value.to_string()),
+                "unrecognized" => model.unrecognized.push(value.to_string()),
+                _ => return None,
+            }
+        }
+        Some(model)
+    }
+}
@@ -0,0 +1,135 @@
+//! The Simulink enumerations
+
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+// Simulink enumeration
+#[derive(Debug)]
+pub(crate) struct Enumeration {
+    // the C typedef name
+    pub name: String,
+    // the enumerators and their values
+    pub members: Vec<(String, i64)>,
+}
+impl Enumeration {
+    // Rust enum
+    //
+    // The enumerators with the value of a previous enumerator, allowed in C but not in Rust,
+    // are associated constants aliasing the variant of the first enumerator with that value
+    pub(crate) fn quote(&self, enum_ord: bool) -> proc_macro2::TokenStream {
+        let c_type = Ident::new(&self.name, Span::call_site());
+        let rs_type = Ident::new(&format!("{}Enum", self.name), Span::call_site());
+        let mut variants = vec![];
+        let mut values = vec![];
+        let mut aliases = vec![];
+        for (name, value) in &self.members {
+            let member = Ident::new(name, Span::call_site());
+            match self.members.iter().find(|(_, v)| v == value) {
+                Some((first, _)) if first != name => {
+                    let variant = Ident::new(first, Span::call_site());
+                    let doc = format!(" Alias of [{}::{}]", rs_type, first);
+                    aliases.push(quote! {
+                        #[doc = #doc]
+                        pub const #member: Self = Self::#variant;
+                    });
+                }
+                _ => {
+                    variants.push(member);
+                    values.push(proc_macro2::Literal::i64_unsuffixed(*value));
+                }
+            }
+        }
+        let aliases = if aliases.is_empty() {
+            quote!()
+        } else {
+            quote! {
+                #[allow(non_upper_case_globals)]
+                impl #rs_type {
+                    #(#aliases)*
+                }
+            }
+        };
+        let ord = if enum_ord {
+            quote!(, PartialOrd, Ord)
+        } else {
+            quote!()
+        };
+        let doc = format!(" Simulink `{}` enumeration", self.name);
+        quote! {
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash #ord)]
+            pub enum #rs_type {
+                #(#variants = #values,)*
+            }
+            impl From<#rs_type> for #c_type {
+                fn from(value: #rs_type) -> Self {
+                    value as Self
+                }
+            }
+            impl TryFrom<#c_type> for #rs_type {
+                type Error = #c_type;
+                fn try_from(value: #c_type) -> Result<Self, Self::Error> {
+                    match value {
+                        #(#values => Ok(Self::#variants),)*
+                        _ => Err(value),
+                    }
+                }
+            }
+            #aliases
+        }
+    }
+}
+
+// Parse a Simulink C header file to extract the enumerations typedefs
+pub(crate) fn parse_enums(file_name: &Path) -> Vec<Enumeration> {
+    let comments = Regex::new(r"(?s)/\*.*?\*/").unwrap();
+    let typedef =
+        Regex::new(r"typedef\s+enum\s*\w*\s*\{(?P<body>[^}]*)\}\s*(?P<name>\w+)\s*;").unwrap();
+    let member = Regex::new(r"^(?P<name>\w+)\s*(?:=\s*(?P<value>-?\s*\w+))?$").unwrap();
+    let header = match fs::read_to_string(file_name) {
+        Ok(header) => comments.replace_all(&header, "").into_owned(),
+        Err(_) => return vec![],
+    };
+    typedef
+        .captures_iter(&header)
+        .map(|caps| {
+            let mut value = -1i64;
+            let members = caps["body"]
+                .split(',')
+                .filter_map(|m| member.captures(m.trim()))
+                .map(|m| {
+                    value = m
+                        .name("value")
+                        .and_then(|v| parse_int(v.as_str()))
+                        .unwrap_or(value + 1);
+                    (m["name"].to_string(), value)
+                })
+                .collect();
+            Enumeration {
+                name: caps["name"].to_string(),
+                members,
+            }
+        })
+        .collect()
+}
+// Parse a C integer literal
+fn parse_int(literal: &str) -> Option<i64> {
+    let literal: String = literal.split_whitespace().collect();
+    let (sign, digits) = match literal.strip_prefix('-') {
+        Some(digits) => (-1, digits),
+        None => (1, literal.as_str()),
+    };
+    let digits = digits.trim_end_matches(['u', 'U', 'l', 'L']);
+    match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => i64::from_str_radix(hex, 16).ok(),
+        None => digits.parse::<i64>().ok(),
+    }
+    .map(|v| sign * v)
+}
@@ -0,0 +1,863 @@
+//! The generators of the wrapper items of the `import!` options and of the crate features
+
+use crate::io::{field, List, Receiver, IO};
+use crate::parse::fnv1a;
+use crate::wrapper::{ModelCall, Wrapper};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+impl Wrapper {
+    // The wrapper is serialized, with the `serde` feature, if it doesn't own the real-time
+    // model and the states have no pointer members
+    fn serializable(&self) -> bool {
+        cfg!(feature = "serde") && self.rtm.is_copy() && !self.states.has_pointers()
+    }
+    // The `serde` derive of the wrapper
+    pub(crate) fn serde_derive(&self) -> TokenStream {
+        if !self.serializable() {
+            return quote!();
+        }
+        quote! {
+            #[derive(::simulink_rs::serde::Serialize, ::simulink_rs::serde::Deserialize)]
+            #[serde(crate = "::simulink_rs::serde")]
+        }
+    }
+    // The `serde` attribute of the wrapper fields left out of the serialization
+    fn serde_skip(&self) -> TokenStream {
+        if !self.serializable() {
+            return quote!();
+        }
+        quote!(#[serde(skip)])
+    }
+    // The `serde` implementations of the Simulink structures
+    pub(crate) fn serde(&self) -> TokenStream {
+        let model_name = self.model.to_string();
+        let serde = |present: bool, list: &List, ty: &str| {
+            if present {
+                list.serde(&format!("{}_{}_T", ty, model_name))
+            } else {
+                quote!()
+            }
+        };
+        let (inputs, outputs, states, continuous, zero_crossings) = (
+            serde(self.has_inputs, &self.inputs, "ExtU"),
+            serde(self.has_outputs, &self.outputs, "ExtY"),
+            serde(
+                self.has_states && !self.states.has_pointers(),
+                &self.states,
+                "DW",
+            ),
+            serde(self.has_continuous, &self.continuous, "X"),
+            serde(self.has_zero_crossings, &self.zero_crossings, "PrevZCX"),
+        );
+        quote! {
+            #inputs
+            #outputs
+            #states
+            #continuous
+            #zero_crossings
+        }
+    }
+    // The outputs range field and its default value, with `output_range`
+    pub(crate) fn output_range_field(&self) -> (TokenStream, TokenStream) {
+        if !self.output_range {
+            return Default::default();
+        }
+        let serde_skip = self.serde_skip();
+        let n_output = self.outputs.0.len();
+        (
+            quote! {
+                // Outputs (min,max) range
+                #serde_skip
+                output_range: Option<[(f64, f64); #n_output]>,
+            },
+            quote!(output_range: Default::default(),),
+        )
+    }
+    // The outputs range update after a step
+    pub(crate) fn output_range_update(&self) -> TokenStream {
+        if !self.output_range {
+            return quote!();
+        }
+        quote!(self.update_output_range();)
+    }
+    // The outputs range methods
+    pub(crate) fn output_range(&self) -> TokenStream {
+        if !self.output_range {
+            return quote!();
+        }
+        let n_output = self.outputs.0.len();
+        let update = self.outputs.range(quote!(range), quote!(self.outputs));
+        let arms = self
+            .outputs
+            .match_index(|i| quote!(self.output_range.map(|range| range[#i])));
+        quote! {
+            // Updates the outputs range
+            fn update_output_range(&mut self) {
+                let range = self
+                    .output_range
+                    .get_or_insert([(f64::INFINITY, f64::NEG_INFINITY); #n_output]);
+                #update
+            }
+            /// Returns the (min,max) range of the output `name` since the first step
+            ///
+            /// The range of an array output is over all the elements of the array
+            pub fn output_range(&self, name: &str) -> Option<(f64, f64)> {
+                match name {
+                    #arms
+                    _ => None,
+                }
+            }
+        }
+    }
+    // The step trace hook field and its default value, with the `trace` feature
+    pub(crate) fn trace_field(&self) -> (TokenStream, TokenStream) {
+        if !self.trace {
+            return Default::default();
+        }
+        let control = &self.control;
+        let serde_skip = self.serde_skip();
+        (
+            quote! {
+                // Step trace hook
+                #serde_skip
+                trace_hook: Option<[<#control TraceHook>]>,
+            },
+            quote!(trace_hook: None,),
+        )
+    }
+    // The trace hook call after a step
+    pub(crate) fn trace_update(&self) -> TokenStream {
+        if !self.trace {
+            return quote!();
+        }
+        let this = Receiver::new(quote!(self), self.cache_aligned);
+        let (inputs, outputs) = (this.inputs(), this.outputs());
+        quote! {
+            if let Some(hook) = self.trace_hook.as_mut() {
+                (hook.0)(&#inputs, &#outputs);
+            }
+        }
+    }
+    // The trace hook type and the trace hook setters
+    pub(crate) fn trace_hook(&self) -> (TokenStream, TokenStream) {
+        if !self.trace {
+            return Default::default();
+        }
+        let Self { model, control, .. } = self;
+        (
+            quote! {
+                // Step trace hook of the wrapper
+                struct [<#control TraceHook>](
+                    Box<dyn FnMut(&[<ExtU_ #model _T>], &[<ExtY_ #model _T>]) + Send>,
+                );
+                impl core::fmt::Debug for [<#control TraceHook>] {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        f.write_str(stringify!([<#control TraceHook>]))
+                    }
+                }
+            },
+            quote! {
+                /// Sets the hook called with the inputs and the outputs after each step
+                pub fn set_trace_hook(
+                    &mut self,
+                    f: Box<dyn FnMut(&[<ExtU_ #model _T>], &[<ExtY_ #model _T>]) + Send>,
+                ) {
+                    self.trace_hook = Some([<#control TraceHook>](f));
+                }
+                /// Removes the trace hook
+                pub fn clear_trace_hook(&mut self) {
+                    self.trace_hook = None;
+                }
+            },
+        )
+    }
+    // The inputs setters with a dead-band, with `deadband`
+    pub(crate) fn deadband(&self) -> TokenStream {
+        if self.deadband {
+            self.inputs.deadband()
+        } else {
+            quote!()
+        }
+    }
+    // The `nalgebra` views of the inputs and outputs
+    pub(crate) fn views(&self) -> TokenStream {
+        if !cfg!(feature = "nalgebra") {
+            return quote!();
+        }
+        let views = [
+            self.outputs.views(quote!(self.outputs), "output", false),
+            self.inputs.views(quote!(self.inputs), "input", false),
+            self.inputs.views(quote!(self.inputs), "input", true),
+        ];
+        quote!(#(#views)*)
+    }
+    // The interior mutability wrapper of a single-rate model, with `interior_mutability`
+    pub(crate) fn cell(&self) -> TokenStream {
+        if !(self.interior_mutability && self.single_rate) {
+            return quote!();
+        }
+        let Self {
+            model,
+            control,
+            wrapper,
+            vis,
+            ..
+        } = self;
+        let inner = Receiver::new(quote!((*self.0.get())), self.cache_aligned);
+        let (inner_inputs, inner_outputs) = (inner.inputs(), inner.outputs());
+        let inputs = if self.has_inputs {
+            quote! {
+                /// Returns a copy of the inputs
+                pub fn inputs(&self) -> [<ExtU_ #model _T>] {
+                    // SAFETY: see `step`
+                    unsafe { #inner_inputs }
+                }
+                /// Sets the inputs
+                pub fn set_inputs(&self, inputs: [<ExtU_ #model _T>]) {
+                    // SAFETY: see `step`
+                    unsafe { #inner_inputs = inputs }
+                }
+            }
+        } else {
+            quote!()
+        };
+        let outputs = if self.has_outputs {
+            quote! {
+                /// Returns a copy of the outputs
+                pub fn outputs(&self) -> [<ExtY_ #model _T>] {
+                    // SAFETY: see `step`
+                    unsafe { #inner_outputs }
+                }
+            }
+        } else {
+            quote!()
+        };
+        let doc = format!(
+            " Single-threaded interior mutability wrapper of [{}]
+ 
+ The controller is stepped and observed through shared references, e.g. behind a `Rc`.
+ The accessors only copy the inputs and outputs in or out of the wrapper,
+ no reference to the controller data outlives an accessor call so the mutable access
+ inside [step](Self::step) never aliases a read.
+ The wrapper is not `Sync` and cannot be shared between threads.",
+            wrapper
+        );
+        quote! {
+            #[doc = #doc]
+            #vis struct [<#control Cell>](core::cell::UnsafeCell<#wrapper>);
+            impl Default for [<#control Cell>] {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+            impl [<#control Cell>] {
+                /// Creates a new controller
+                pub fn new() -> Self {
+                    Self(core::cell::UnsafeCell::new(#wrapper::new()))
+                }
+                /// Steps the controller
+                pub fn step(&self) {
+                    // SAFETY: the wrapper is not `Sync` and no reference to its content escapes an accessor
+                    unsafe { (*self.0.get()).step() }
+                }
+                #inputs
+                #outputs
+                /// Consumes the wrapper, returning the controller
+                pub fn into_inner(self) -> #wrapper {
+                    self.0.into_inner()
+                }
+            }
+        }
+    }
+    // The finite-difference Jacobian of a `Copy` single-rate wrapper
+    pub(crate) fn jacobian(&self) -> TokenStream {
+        if !(self.copy && self.single_rate && !self.no_std) {
+            return quote!();
+        }
+        let (inputs, inputs_ty): (Vec<_>, Vec<_>) = self
+            .inputs
+            .elements(quote!(this.inputs))
+            .into_iter()
+            .filter(|(_, ty)| ty.is_float())
+            .map(|(input, ty)| (input, ty.ty()))
+            .unzip();
+        let n_input = inputs.len();
+        let outputs: Vec<_> = self
+            .outputs
+            .elements(quote!(this.outputs))
+            .into_iter()
+            .map(|(output, ty)| ty.to_f64(output))
+            .collect();
+        quote! {
+            /// Returns the finite-difference Jacobian of the outputs with respect to the inputs
+            ///
+            /// Each floating point input, array inputs element-wise, is perturbed by `eps` and a copy
+            /// of the controller in its current state is stepped, the controller is left unchanged.
+            /// The outputs differences are divided by the perturbation actually applied to the input,
+            /// `eps` rounded to the precision of the input type.
+            /// The Jacobian rows are the outputs, array outputs element-wise, and the columns the
+            /// floating point inputs, both in the order of the Simulink structures: the integer and
+            /// boolean inputs are left out of the columns.
+            pub fn jacobian(&self, eps: f64) -> Vec<Vec<f64>> {
+                // the perturbations return the step applied to the input
+                let perturbations: [fn(&mut Self, f64) -> f64; #n_input] = [#(|this: &mut Self, eps: f64| {
+                    let u = #inputs;
+                    #inputs = u + eps as #inputs_ty;
+                    #inputs as f64 - u as f64
+                }),*];
+                let outputs = |this: &Self| -> Vec<f64> { vec![#(#outputs),*] };
+                let mut nominal = *self;
+                nominal.step();
+                let nominal = outputs(&nominal);
+                let mut jacobian = vec![vec![0f64; #n_input]; nominal.len()];
+                for (j, perturb) in perturbations.iter().enumerate() {
+                    let mut this = *self;
+                    let step = perturb(&mut this, eps);
+                    this.step();
+                    for (i, (y, y0)) in outputs(&this).iter().zip(&nominal).enumerate() {
+                        jacobian[i][j] = (y - y0) / step;
+                    }
+                }
+                jacobian
+            }
+        }
+    }
+    // The checkpoint error and the checkpoint serialization methods
+    pub(crate) fn checkpoint(&self) -> (TokenStream, TokenStream) {
+        if self.no_std {
+            return Default::default();
+        }
+        let Self {
+            control,
+            inputs,
+            outputs,
+            states,
+            ..
+        } = self;
+        let (states_layout, states_size, states_to_bytes, states_from_bytes) =
+            match &self.states_place(&self.this_self) {
+                Some(place) => (
+                    states.layout(),
+                    states.byte_size(),
+                    {
+                        let to_bytes = states.to_le_bytes(quote!(bytes), quote!(states));
+                        quote!(unsafe {
+                            let states = &#place;
+                            #to_bytes
+                        })
+                    },
+                    {
+                        let from_bytes = states.read_le_bytes(quote!(bytes), place.clone());
+                        quote!(unsafe { #from_bytes })
+                    },
+                ),
+                None => (String::new(), quote!(0), quote!(), quote!()),
+            };
+        let fingerprint = fnv1a(&format!(
+            "{}|{}|{}",
+            inputs.layout(),
+            outputs.layout(),
+            states_layout
+        ));
+        let (inputs_size, outputs_size) = (inputs.byte_size(), outputs.byte_size());
+        let inputs_to_bytes = inputs.to_le_bytes(quote!(bytes), quote!(self.inputs));
+        let outputs_to_bytes = outputs.to_le_bytes(quote!(bytes), quote!(self.outputs));
+        let inputs_from_bytes = inputs.read_le_bytes(quote!(bytes), quote!(self.inputs));
+        let outputs_from_bytes = outputs.read_le_bytes(quote!(bytes), quote!(self.outputs));
+        (
+            quote! {
+                /// Invalid checkpoint
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum [<#control CheckpointError>] {
+                    /// The checkpoint isn't as long as expected
+                    Length { expected: usize, found: usize },
+                    /// The checkpoint format version isn't supported
+                    Version(u32),
+                    /// The checkpoint was written by a model with a different layout
+                    Fingerprint { expected: u32, found: u32 },
+                }
+                impl core::fmt::Display for [<#control CheckpointError>] {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        match self {
+                            Self::Length { expected, found } => write!(
+                                f,
+                                "checkpoint length is {} bytes, expected {} bytes",
+                                found, expected
+                            ),
+                            Self::Version(version) => {
+                                write!(f, "unsupported checkpoint version {}", version)
+                            }
+                            Self::Fingerprint { expected, found } => write!(
+                                f,
+                                "checkpoint fingerprint {:#010x} doesn't match the model fingerprint {:#010x}",
+                                found, expected
+                            ),
+                        }
+                    }
+                }
+                impl core::error::Error for [<#control CheckpointError>] {}
+            },
+            quote! {
+                /// Version of the checkpoint format
+                pub const CHECKPOINT_VERSION: u32 = 1;
+                /// Fingerprint of the checkpoint layout
+                ///
+                /// FNV-1a hash of the names, C types and sizes of the inputs, outputs and states
+                pub const CHECKPOINT_FINGERPRINT: u32 = #fingerprint;
+                /// Serializes the inputs, outputs and states
+                ///
+                /// The checkpoint starts with a 8 bytes header: the [CHECKPOINT_VERSION](Self::CHECKPOINT_VERSION)
+                /// followed by the [CHECKPOINT_FINGERPRINT](Self::CHECKPOINT_FINGERPRINT), both little-endian.
+                /// The inputs, outputs and states little-endian bytes follow in the order of the Simulink structures.
+                pub fn checkpoint(&self) -> Vec<u8> {
+                    let mut bytes = Vec::with_capacity(8 + #inputs_size + #outputs_size + #states_size);
+                    bytes.extend_from_slice(&Self::CHECKPOINT_VERSION.to_le_bytes());
+                    bytes.extend_from_slice(&Self::CHECKPOINT_FINGERPRINT.to_le_bytes());
+                    #inputs_to_bytes
+                    #outputs_to_bytes
+                    #states_to_bytes
+                    bytes
+                }
+                /// Restores the inputs, outputs and states from a [checkpoint](Self::checkpoint)
+                ///
+                /// The controller is left unchanged if the checkpoint version, fingerprint or length is invalid
+                pub fn restore_checkpoint(&mut self, bytes: &[u8]) -> Result<(), [<#control CheckpointError>]> {
+                    let expected = 8 + #inputs_size + #outputs_size + #states_size;
+                    if bytes.len() < 8 {
+                        return Err([<#control CheckpointError>]::Length { expected, found: bytes.len() });
+                    }
+                    let version = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+                    if version != Self::CHECKPOINT_VERSION {
+                        return Err([<#control CheckpointError>]::Version(version));
+                    }
+                    let fingerprint = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+                    if fingerprint != Self::CHECKPOINT_FINGERPRINT {
+                        return Err([<#control CheckpointError>]::Fingerprint {
+                            expected: Self::CHECKPOINT_FINGERPRINT,
+                            found: fingerprint,
+                        });
+                    }
+                    if bytes.len() != expected {
+                        return Err([<#control CheckpointError>]::Length { expected, found: bytes.len() });
+                    }
+                    let mut bytes = &bytes[8..];
+                    #inputs_from_bytes
+                    #outputs_from_bytes
+                    #states_from_bytes
+                    let _ = bytes;
+                    Ok(())
+                }
+            },
+        )
+    }
+    // The snapshot type and the snapshot methods, the snapshot copies the Simulink structures,
+    // unless the states have pointer members, or else the states variables that aren't skipped
+    pub(crate) fn snapshot(&self) -> (TokenStream, TokenStream) {
+        if self.states.has_pointers() {
+            return (quote!(), quote!());
+        }
+        let Self {
+            model,
+            control,
+            this_self,
+            skip_states,
+            ..
+        } = self;
+        let skipped = |io: &IO| skip_states.iter().any(|state| io.method() == *state);
+        let mut fields = vec![];
+        let mut copies = vec![];
+        let mut restores = vec![];
+        if self.has_inputs {
+            let inputs = this_self.inputs();
+            fields.push(quote!(
+                /// Inputs Simulink structure
+                pub inputs: [<ExtU_ #model _T>]
+            ));
+            copies.push(quote!(inputs: #inputs));
+            restores.push(quote!(#inputs = snapshot.inputs;));
+        }
+        if self.has_outputs {
+            let outputs = this_self.outputs();
+            fields.push(quote!(
+                /// Outputs Simulink structure
+                pub outputs: [<ExtY_ #model _T>]
+            ));
+            copies.push(quote!(outputs: #outputs));
+            restores.push(quote!(#outputs = snapshot.outputs;));
+        }
+        let mut snapshot_states = quote!();
+        match self.states_place(this_self) {
+            Some(states) if skip_states.is_empty() => {
+                fields.push(quote!(
+                    /// States Simulink structure
+                    pub states: [<DW_ #model _T>]
+                ));
+                copies.push(quote!(states: unsafe { #states }));
+                restores.push(quote!(unsafe { #states = snapshot.states };));
+            }
+            Some(states) => {
+                let kept: Vec<_> = self.states.0.iter().filter(|io| !skipped(io)).collect();
+                // the states are named after the structure members, e.g. `bus_signal` for `bus.signal`
+                let names: Vec<_> = kept
+                    .iter()
+                    .map(|io| field(&io.name.replace('.', "_")))
+                    .collect();
+                let vars: Vec<_> = kept.iter().map(|io| io.var()).collect();
+                let tys = kept.iter().map(|io| io.array_ty(io.ty.ty()));
+                let docs = kept.iter().map(|io| format!(" The state `{}`", io.name));
+                snapshot_states = quote! {
+                    /// States variables of a snapshot, without the skipped states
+                    #[derive(Debug, Clone, Copy)]
+                    #[allow(non_snake_case)]
+                    pub struct [<#control SnapshotStates>] {
+                        #(#[doc = #docs] pub #names: #tys),*
+                    }
+                };
+                fields.push(quote!(
+                    /// States variables
+                    pub states: [<#control SnapshotStates>]
+                ));
+                copies.push(quote!(states: unsafe {
+                    [<#control SnapshotStates>] {
+                        #(#names: #states.#vars),*
+                    }
+                }));
+                restores.push(quote!(unsafe { #(#states.#vars = snapshot.states.#names;)* }));
+            }
+            None => (),
+        }
+        if self.has_continuous && !self.continuous.has_pointers() {
+            let continuous = this_self.continuous();
+            fields.push(quote!(
+                /// Continuous states Simulink structure
+                pub states_continuous: [<X_ #model _T>]
+            ));
+            copies.push(quote!(states_continuous: #continuous));
+            restores.push(quote!(#continuous = snapshot.states_continuous;));
+        }
+        if self.has_zero_crossings {
+            let zero_crossings = this_self.zero_crossings();
+            fields.push(quote!(
+                /// Previous zero-crossings Simulink structure
+                pub zero_crossings: [<PrevZCX_ #model _T>]
+            ));
+            copies.push(quote!(zero_crossings: #zero_crossings));
+            restores.push(quote!(#zero_crossings = snapshot.zero_crossings;));
+        }
+        (
+            quote! {
+                #snapshot_states
+                /// Snapshot of the inputs, outputs and states of a controller
+                #[derive(Debug, Clone, Copy)]
+                pub struct [<#control Snapshot>] {
+                    #(#fields),*
+                }
+            },
+            quote! {
+                /// Returns a copy of the inputs, outputs and states
+                pub fn snapshot(&self) -> [<#control Snapshot>] {
+                    [<#control Snapshot>] {
+                        #(#copies),*
+                    }
+                }
+                /// Restores the inputs, outputs and states of a [snapshot](Self::snapshot)
+                ///
+                /// Stepping the restored controller replays the steps following the snapshot
+                pub fn restore(&mut self, snapshot: &[<#control Snapshot>]) {
+                    #(#restores)*
+                }
+            },
+        )
+    }
+    // The names of the outputs differing from expected outputs
+    pub(crate) fn outputs_diff(&self) -> TokenStream {
+        if !self.has_outputs || self.no_std {
+            return quote!();
+        }
+        let model = &self.model;
+        let diff = self
+            .outputs
+            .diff(quote!(diff), quote!(self.outputs), quote!(expected));
+        quote! {
+            /// Returns the names of the outputs that differ from the `expected` outputs by more than `tolerance`
+            ///
+            /// Array outputs differ if any of the elements differ
+            pub fn outputs_diff(&self, expected: &[<ExtY_ #model _T>], tolerance: f64) -> Vec<&'static str> {
+                let mut diff = vec![];
+                #diff
+                diff
+            }
+        }
+    }
+    // The trace mismatch type and the replay methods of a single-rate model with inputs
+    // and outputs
+    pub(crate) fn replay(&self) -> (TokenStream, TokenStream) {
+        if !(self.single_rate && self.has_inputs && self.has_outputs) {
+            return Default::default();
+        }
+        let Self {
+            model,
+            control,
+            this,
+            this_self,
+            ..
+        } = self;
+        let (self_inputs, self_outputs) = (this_self.inputs(), this_self.outputs());
+        let this_outputs = this.outputs();
+        let first_diff = self.outputs.first_diff(
+            &this_outputs,
+            &quote!(expected),
+            cfg!(feature = "approx_eq"),
+        );
+        let this_inputs = this.inputs();
+        let trace_mismatch = quote! {
+            /// First diverging tick of a trace verification
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct [<#control TraceMismatch>] {
+                /// The index of the tick
+                pub tick: usize,
+                /// The name of the first diverging output
+                pub field: &'static str,
+            }
+            impl core::fmt::Display for [<#control TraceMismatch>] {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "output `{}` diverged at tick {}", self.field, self.tick)
+                }
+            }
+            impl core::error::Error for [<#control TraceMismatch>] {}
+        };
+        let replay_and_assert = self.with_std(quote! {
+            /// Steps the controller with each of the `inputs` and compares the outputs with the `expected` outputs
+            ///
+            /// Stops at the first step where the outputs differ by more than `tolerance`
+            /// and returns the index of the step and the names of the diverging outputs.
+            /// The number of steps is the smallest of the `inputs` and `expected` lengths.
+            pub fn replay_and_assert(
+                &mut self,
+                inputs: &[[<ExtU_ #model _T>]],
+                expected: &[[<ExtY_ #model _T>]],
+                tolerance: f64,
+            ) -> Result<(), [<#control Divergence>]> {
+                for (step, (inputs, expected)) in inputs.iter().zip(expected.iter()).enumerate() {
+                    #self_inputs = *inputs;
+                    self.step();
+                    let signals = self.outputs_diff(expected, tolerance);
+                    if !signals.is_empty() {
+                        return Err([<#control Divergence>] { step, signals });
+                    }
+                }
+                Ok(())
+            }
+        });
+        let replay = quote! {
+            /// Steps the controller with the `inputs` and returns the outputs
+            ///
+            /// The inputs are kept for the next steps, e.g. `inputs.into_iter().map(|u| model.step_with(u))`
+            pub fn step_with(&mut self, inputs: [<ExtU_ #model _T>]) -> [<ExtY_ #model _T>] {
+                #self_inputs = inputs;
+                self.step();
+                #self_outputs
+            }
+            /// Steps the controller once per `inputs`, writing each step outputs into `outputs`
+            ///
+            /// # Panics
+            ///
+            /// Panics if `inputs` and `outputs` have different lengths
+            pub fn rollout(&mut self, inputs: &[[<ExtU_ #model _T>]], outputs: &mut [[<ExtY_ #model _T>]]) {
+                assert_eq!(
+                    inputs.len(),
+                    outputs.len(),
+                    "rollout inputs and outputs lengths differ"
+                );
+                for (inputs, outputs) in inputs.iter().zip(outputs.iter_mut()) {
+                    #self_inputs = *inputs;
+                    self.step();
+                    *outputs = #self_outputs;
+                }
+            }
+            #replay_and_assert
+            /// Steps a new controller with each of the `inputs` and compares the outputs with the `expected` outputs
+            ///
+            /// The outputs are compared field by field as by their `PartialEq` implementation,
+            /// or as by their `approx_eq` method with the `approx_eq` feature.
+            /// Returns the first diverging tick and output, e.g. against a reference trace of the Simulink model.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `inputs` and `expected` have different lengths
+            pub fn verify_trace(
+                inputs: &[[<ExtU_ #model _T>]],
+                expected: &[[<ExtY_ #model _T>]],
+            ) -> Result<(), [<#control TraceMismatch>]> {
+                assert_eq!(
+                    inputs.len(),
+                    expected.len(),
+                    "trace inputs and expected outputs lengths differ"
+                );
+                let mut this = Self::new();
+                for (tick, (inputs, expected)) in inputs.iter().zip(expected).enumerate() {
+                    #this_inputs = *inputs;
+                    this.step();
+                    let diff: Option<&'static str> = #first_diff;
+                    if let Some(field) = diff {
+                        return Err([<#control TraceMismatch>] { tick, field });
+                    }
+                }
+                Ok(())
+            }
+        };
+        (trace_mismatch, replay)
+    }
+    // The first diverging step of a replay
+    pub(crate) fn divergence(&self) -> TokenStream {
+        let control = &self.control;
+        self.with_std(quote! {
+            /// First diverging step of a replay
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct [<#control Divergence>] {
+                /// The index of the step
+                pub step: usize,
+                /// The names of the diverging outputs
+                pub signals: Vec<&'static str>,
+            }
+            impl core::fmt::Display for [<#control Divergence>] {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "outputs {:?} diverged at step {}", self.signals, self.step)
+                }
+            }
+            impl core::error::Error for [<#control Divergence>] {}
+        })
+    }
+    // The step guarded against unwinding panics, with `catch_unwind`
+    pub(crate) fn try_step(&self) -> TokenStream {
+        if !(self.catch_unwind && self.single_rate && !self.no_std) {
+            return quote!();
+        }
+        let model = &self.model;
+        let ModelCall { setup, ptr, post } = &self.rtm_self;
+        let step_args = self.step_fn.quote(ptr, &self.this_self);
+        let (range_update, trace_update) = (self.output_range_update(), self.trace_update());
+        quote! {
+            /// Steps the controller, catching any panic unwinding out of the model
+            ///
+            /// Returns the panic payload as an error if the step panicked
+            pub fn try_step(&mut self) -> std::thread::Result<()> {
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    #setup
+                    unsafe {
+                        [<#model _step>](#step_args)
+                    }
+                    #post
+                    #range_update
+                    #trace_update
+                }))
+            }
+        }
+    }
+    // The step after a perturbation of the inputs and the states, with the `testing` feature
+    pub(crate) fn step_with_disturbance(&self) -> TokenStream {
+        let model = &self.model;
+        let self_inputs = self.this_self.inputs();
+        match self.states_place(&self.this_self) {
+            Some(states) if cfg!(feature = "testing") && self.single_rate && self.has_inputs => {
+                quote! {
+                    /// Steps the controller after the inputs and the states are perturbed by `f`
+                    ///
+                    /// For fault-injection tests only, the states are internal to the model
+                    pub fn step_with_disturbance<F>(&mut self, mut f: F)
+                    where
+                        F: FnMut(&mut [<ExtU_ #model _T>], &mut [<DW_ #model _T>]),
+                    {
+                        f(&mut #self_inputs, unsafe { &mut #states });
+                        self.step();
+                    }
+                }
+            }
+            _ => quote!(),
+        }
+    }
+    // The names and values of the inputs and outputs, as vectors and as maps
+    pub(crate) fn named(&self) -> TokenStream {
+        let named = |list: &List, src: TokenStream| {
+            let names = list.names();
+            let values = list
+                .elements(src)
+                .into_iter()
+                .map(|(value, ty)| ty.to_f64(value));
+            quote!(vec![#((#names, #values)),*])
+        };
+        let (inputs_named, outputs_named) = (
+            named(&self.inputs, quote!(self.inputs)),
+            named(&self.outputs, quote!(self.outputs)),
+        );
+        let named_methods = self.with_std(quote! {
+            /// Returns the names and the values of the inputs
+            ///
+            /// The inputs are in the order of the Simulink structure, arrays are expanded into
+            /// elements named `name[i]`
+            pub fn inputs_named(&self) -> Vec<(&'static str, f64)> {
+                #inputs_named
+            }
+            /// Returns the names and the values of the outputs
+            ///
+            /// The outputs are in the order of the Simulink structure, arrays are expanded into
+            /// elements named `name[i]`
+            pub fn outputs_named(&self) -> Vec<(&'static str, f64)> {
+                #outputs_named
+            }
+        });
+        // the maps of the signals names to their values, with the `std` hash maps
+        let maps_methods = self.with_std(quote! {
+            /// Returns the map of the inputs names to their values
+            ///
+            /// The arrays are expanded into elements named `name[i]`, as in [inputs_named](Self::inputs_named)
+            pub fn inputs_map(&self) -> std::collections::HashMap<String, f64> {
+                self.inputs_named()
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value))
+                    .collect()
+            }
+            /// Returns the map of the outputs names to their values
+            ///
+            /// The arrays are expanded into elements named `name[i]`, as in [outputs_named](Self::outputs_named)
+            pub fn outputs_map(&self) -> std::collections::HashMap<String, f64> {
+                self.outputs_named()
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value))
+                    .collect()
+            }
+        });
+        quote! {
+            #named_methods
+            #maps_methods
+        }
+    }
+    // The outputs telemetry frame
+    pub(crate) fn telemetry_frame(&self) -> TokenStream {
+        let telemetry = self
+            .outputs
+            .to_le_bytes(quote!(frame), quote!(self.outputs));
+        self.with_std(quote! {
+            /// Returns the outputs telemetry frame
+            ///
+            /// The frame starts with a 4 bytes header: the 16 bits model ID followed by
+            /// the 16 bits length of the payload, both little-endian.
+            /// The model ID is the [IO_FINGERPRINT](Self::IO_FINGERPRINT) folded on 16 bits.
+            /// The payload is the outputs little-endian bytes in the order of the Simulink structure.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the payload is longer than `u16::MAX` bytes
+            pub fn telemetry_frame(&self) -> Vec<u8> {
+                let id = (Self::IO_FINGERPRINT as u16) ^ ((Self::IO_FINGERPRINT >> 16) as u16);
+                let mut frame = vec![0u8; 4];
+                frame[..2].copy_from_slice(&id.to_le_bytes());
+                #telemetry
+                let length = u16::try_from(frame.len() - 4).expect("telemetry payload too long");
+                frame[2..4].copy_from_slice(&length.to_le_bytes());
+                frame
+            }
+        })
+    }
+}
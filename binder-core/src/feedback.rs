@@ -0,0 +1,68 @@
+//! The `feedback!` macro
+
+use crate::args::Feedback;
+use crate::io::List;
+use crate::model::Model;
+use crate::parse::{headers, sys_dir};
+use crate::types::TypeMap;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The `feedback!` macro expansion
+pub fn feedback(input: TokenStream) -> TokenStream {
+    let Feedback {
+        plant,
+        controller,
+        dir,
+    } = match syn::parse2(input) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    let sys = sys_dir(dir.as_ref());
+    let mut types = TypeMap::default();
+    for file_name in headers(&sys) {
+        types.parse(&file_name);
+    }
+    let mut plant = match Model::parse(&sys.join(format!("{}.h", plant))) {
+        Ok(model) => model,
+        Err(e) => return syn::Error::new(plant.span(), e).to_compile_error(),
+    };
+    plant.resolve(&types);
+    let mut controller = match Model::parse(&sys.join(format!("{}.h", controller))) {
+        Ok(model) => model,
+        Err(e) => return syn::Error::new(controller.span(), e).to_compile_error(),
+    };
+    controller.resolve(&types);
+
+    // a missing structure has no signal to connect
+    let none = List::default();
+    diagnostic!("| {} -> {}:", plant.name, controller.name);
+    let plant_to_controller = controller.inputs.as_ref().unwrap_or(&none).connect(
+        quote!(controller.inputs),
+        plant.outputs.as_ref().unwrap_or(&none),
+        quote!(plant.outputs),
+    );
+    diagnostic!("| {} -> {}:", controller.name, plant.name);
+    let controller_to_plant = plant.inputs.as_ref().unwrap_or(&none).connect(
+        quote!(plant.inputs),
+        controller.outputs.as_ref().unwrap_or(&none),
+        quote!(controller.outputs),
+    );
+
+    let plant = plant.name;
+    let controller = controller.name;
+    let code = quote! {
+        /// Steps the plant and the controller in closed loop
+        ///
+        /// The controller is stepped first with the plant outputs
+        /// and then the plant is stepped with the controller outputs
+        pub fn feedback_step(plant: &mut #plant, controller: &mut #controller) {
+            #plant_to_controller
+            controller.step();
+            #controller_to_plant
+            plant.step();
+        }
+    };
+    code
+}
@@ -0,0 +1,299 @@
+//! The `import!` macro
+
+use crate::args::Args;
+use crate::bindings::quote_bindings;
+use crate::io::List;
+use crate::model::Model;
+use crate::parse::{model_header, named_model_header, sources, sys_dir, EXCLUDED_HEADERS};
+use crate::wrapper::Wrapper;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+use syn::Result;
+
+/// The `import!` macro expansion
+pub fn import(input: TokenStream) -> TokenStream {
+    match syn::parse2(input).and_then(expand) {
+        Ok(code) => code,
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+// The bindings and the wrapper of the Simulink model of the `import!` arguments
+fn expand(args: Args) -> Result<TokenStream> {
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).to_path_buf();
+    // the explicit header directory or the `sys` directory
+    let header = args
+        .header
+        .as_ref()
+        .map(|header| (root.join(header.value()), header.span()));
+    let sys = match &header {
+        Some((file_name, _)) => file_name
+            .parent()
+            .map_or_else(|| root.clone(), Path::to_path_buf),
+        None => sys_dir(args.dir.as_ref()),
+    };
+    let bindings = match &args.bindings_path {
+        Some(path) => quote! {
+            #[allow(unused_imports)]
+            use #path::*;
+        },
+        None if args.bindings => quote_bindings(&sys, args.enum_ord),
+        None => quote!(),
+    };
+    let (file_name, span) = match header {
+        Some(header) => header,
+        None => {
+            let excluded = args.exclude.clone().unwrap_or_else(|| {
+                EXCLUDED_HEADERS
+                    .iter()
+                    .map(|suffix| suffix.to_string())
+                    .collect()
+            });
+            let (found, span) = match &args.model_name {
+                Some(model_name) => (
+                    named_model_header(&sys, &model_name.value(), &excluded),
+                    model_name.span(),
+                ),
+                None => (
+                    model_header(&sys, &args.control, &excluded),
+                    args.control.span(),
+                ),
+            };
+            (found.map_err(|e| syn::Error::new(span, e))?, span)
+        }
+    };
+    let out_dir = env::var_os("OUT_DIR").map(PathBuf::from);
+    let model = Model::load(&file_name, &sys, args.precision, out_dir.as_deref())
+        .map_err(|e| syn::Error::new(span, e))?;
+    let sys_sources = sources(&sys);
+    let tracked = tracked(
+        std::iter::once(file_name.as_path()).chain(sys_sources.iter().map(PathBuf::as_path)),
+    );
+    if let Ok(out_dir) = env::var("OUT_DIR") {
+        let metadata = Path::new(&out_dir).join(format!("{}_metadata.json", model.name));
+        if let Err(e) = fs::write(&metadata, model.json()) {
+            diagnostic!("| failed to write {:?}: {}", metadata, e);
+        }
+    }
+    let metadata = model.metadata();
+    let unrecognized = unrecognized(&model.unrecognized, span)?;
+    let header = fs::read_to_string(&file_name).unwrap_or_default();
+    let wrapper = Wrapper::new(&args, model, header)?;
+    let new_from_ic = wrapper.new_from_ic()?;
+
+    let alias = wrapper.alias();
+    let structure = wrapper.structure();
+    let (trace_hook, trace_method) = wrapper.trace_hook();
+    let (trace_mismatch, replay) = wrapper.replay();
+    let (incompatible_model, model_checksum) = wrapper.checksum();
+    let (checkpoint_error, checkpoint) = wrapper.checkpoint();
+    let (snapshot_struct, snapshot) = wrapper.snapshot();
+    let (inputs_builder, with_inputs) = wrapper.inputs_builder();
+    let (len_error, set_inputs) = wrapper.set_inputs();
+    let (params_impl, set_parameters) = wrapper.parameters();
+    let aligned_storage = wrapper.aligned_storage();
+    let cell = wrapper.cell();
+    let divergence = wrapper.divergence();
+    let structures_default = wrapper.structures_default();
+    let flat_conversions = wrapper.flat_conversions();
+    let comparisons = wrapper.comparisons();
+    let signal_index = wrapper.signal_index();
+    let layout_assertions = wrapper.layout_assertions();
+    let simulink_model = wrapper.simulink_model();
+    let display = wrapper.display();
+    let serde_impl = wrapper.serde();
+    let layout_constants = wrapper.layout_constants();
+    let sample_time = wrapper.sample_time();
+    let raw_pointers = wrapper.raw_pointers();
+    let new = wrapper.constructors();
+    let reset = wrapper.reset();
+    let step = wrapper.steps();
+    let derivatives = wrapper.derivatives();
+    let states_accessors = wrapper.states_accessors();
+    let try_step = wrapper.try_step();
+    let step_with_disturbance = wrapper.step_with_disturbance();
+    let terminate = wrapper.terminate();
+    let range_method = wrapper.output_range();
+    let outputs_diff = wrapper.outputs_diff();
+    let deadband = wrapper.deadband();
+    let accessors = wrapper.accessors();
+    let views = wrapper.views();
+    let signals = wrapper.signals();
+    let named = wrapper.named();
+    let jacobian = wrapper.jacobian();
+    let telemetry_frame = wrapper.telemetry_frame();
+    let Wrapper { wrapper, vis, .. } = &wrapper;
+
+    let code = quote! {
+        #bindings
+
+        #alias
+
+        #tracked
+
+        paste::paste!{
+        #structure
+        #trace_hook
+        #aligned_storage
+        #cell
+        #divergence
+        #trace_mismatch
+        #incompatible_model
+        #checkpoint_error
+        #snapshot_struct
+        #inputs_builder
+        #structures_default
+        #flat_conversions
+        #comparisons
+        #signal_index
+        #layout_assertions
+        #unrecognized
+        #simulink_model
+        #len_error
+        #display
+        #params_impl
+        #serde_impl
+        impl #wrapper {
+            #layout_constants
+            #model_checksum
+            #metadata
+            #sample_time
+            #checkpoint
+            #snapshot
+            #raw_pointers
+            #new
+            #with_inputs
+            #new_from_ic
+            #reset
+            #step
+            #derivatives
+            #states_accessors
+            #try_step
+            #step_with_disturbance
+            #terminate
+            #range_method
+            #trace_method
+            #outputs_diff
+            #deadband
+            #accessors
+            #views
+            #signals
+            #set_inputs
+            #set_parameters
+            #named
+            #jacobian
+            #telemetry_frame
+            #replay
+        }        }
+    };
+    // the items are written in the module, the items of the parent module, e.g. the
+    // hand written bindings, are shadowed by the included bindings
+    Ok(match &args.module {
+        Some(module) => quote! {
+            #vis mod #module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #code
+            }
+        },
+        None => code,
+    })
+}
+
+// The variables of unrecognized types, reported together as a warning or, with the
+// `strict` feature, an error
+fn unrecognized(variables: &[String], span: Span) -> Result<TokenStream> {
+    if variables.is_empty() {
+        return Ok(quote!());
+    }
+    let note = format!(
+        "the C types of the members {} aren't recognized, the members are bound as f64, \
+         declare the types aliases in the headers of the model directory",
+        variables.join(", ")
+    );
+    if cfg!(feature = "strict") {
+        return Err(syn::Error::new(span, note));
+    }
+    diagnostic!("| ! {}", note);
+    Ok(quote! {
+        const _: () = {
+            #[deprecated(note = #note)]
+            const UNRECOGNIZED_TYPE: () = ();
+            UNRECOGNIZED_TYPE
+        };
+    })
+}
+
+// Anonymous constants including the bytes of the `files` read by the macros, the crate
+// depends on the files and the macros are expanded again when the files change
+fn tracked<'a>(files: impl IntoIterator<Item = &'a Path>) -> proc_macro2::TokenStream {
+    let mut paths: Vec<String> = files
+        .into_iter()
+        .filter_map(|file| fs::canonicalize(file).ok())
+        .filter_map(|path| path.to_str().map(str::to_string))
+        .collect();
+    paths.sort();
+    paths.dedup();
+    quote!(#(const _: &[u8] = include_bytes!(#paths);)*)
+}
+
+// Parse the initial conditions file: one `name,value[,value...]` line per state,
+// lines starting with `#` are comments
+pub(crate) fn parse_ic(
+    init: &syn::LitStr,
+    states: &List,
+    target: &proc_macro2::TokenStream,
+) -> Result<proc_macro2::TokenStream> {
+    let file_name = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(init.value());
+    let ic = fs::read_to_string(&file_name).map_err(|e| {
+        syn::Error::new(
+            init.span(),
+            format!("failed to read {:?}: {}", file_name, e),
+        )
+    })?;
+    let mut assignments = tracked([file_name.as_path()]);
+    for line in ic.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split(',').map(str::trim);
+        let name = fields.next().unwrap_or_default();
+        let io = states.0.iter().find(|io| io.name == name).ok_or_else(|| {
+            syn::Error::new(
+                init.span(),
+                format!("`{}` is not a state of the model", name),
+            )
+        })?;
+        let values = fields
+            .map(|value| value.parse::<f64>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| {
+                syn::Error::new(
+                    init.span(),
+                    format!("invalid value for state `{}`: {}", name, e),
+                )
+            })?;
+        let size = io.len();
+        if values.len() != size {
+            return Err(syn::Error::new(
+                init.span(),
+                format!(
+                    "state `{}` expects {} value(s), found {}",
+                    name,
+                    size,
+                    values.len()
+                ),
+            ));
+        }
+        let var = io.var();
+        let value = io.nest(values.into_iter().map(|v| io.ty.literal(v)).collect());
+        assignments = quote! {
+            #assignments
+            #target.#var = #value;
+        };
+    }
+    Ok(assignments)
+}
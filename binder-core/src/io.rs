@@ -0,0 +1,1218 @@
+//! The variables of the Simulink structures and their code
+
+use crate::parse::json_string;
+use crate::types::{Primitive, TypeMap};
+use proc_macro2::{Ident, Span};
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+
+// The names bindgen suffixes with `_` in the bindings, the Rust keywords and primitive types
+const BINDGEN_RESERVED: &[&str] = &[
+    "abstract", "alignof", "as", "async", "await", "become", "bool", "box", "break", "const",
+    "continue", "crate", "do", "dyn", "else", "enum", "extern", "f32", "f64", "false", "final",
+    "fn", "for", "i128", "i16", "i32", "i64", "i8", "if", "impl", "in", "isize", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "offsetof", "override", "priv", "proc", "pub", "pure",
+    "ref", "return", "Self", "self", "sizeof", "static", "str", "struct", "super", "trait", "true",
+    "try", "type", "typeof", "u128", "u16", "u32", "u64", "u8", "unsafe", "unsized", "use",
+    "usize", "virtual", "where", "while", "yield", "_",
+];
+
+// Rust field of the C structure member `member` in the bindgen bindings, e.g. `type_` for `type`,
+// prefixed with `n_` if it starts with a digit
+pub(crate) fn field(member: &str) -> Ident {
+    if BINDGEN_RESERVED.contains(&member) {
+        format_ident!("{}_", member)
+    } else if member.starts_with(|c: char| c.is_ascii_digit()) {
+        format_ident!("n_{}", member)
+    } else {
+        Ident::new(member, Span::call_site())
+    }
+}
+
+// Simulink inputs/outputs
+#[derive(Debug, Default)]
+pub(crate) struct IO {
+    // i/o variable name
+    pub name: String,
+    // i/o variable dimensions, outermost first, empty for scalars
+    pub size: Vec<usize>,
+    // i/o variable C type
+    pub ctype: String,
+    // i/o variable Rust type
+    pub ty: Primitive,
+    // types of the nested structures members enclosing the variable, outermost first,
+    // `__bindgen_ty_<n>` for the n-th anonymous structure of the enclosing structure
+    pub structs: Vec<String>,
+}
+impl IO {
+    // Creates a new IO, `size` are the C array dimensions e.g. `[6][12]`
+    pub(crate) fn new(name: &str, size: Option<&str>, ctype: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            size: size
+                .map(|s| {
+                    s.split(|c: char| !c.is_ascii_digit())
+                        .filter_map(|n| n.parse().ok())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            ctype: ctype.to_string(),
+            ty: Primitive::default(),
+            structs: vec![],
+        }
+    }
+    // Rust variable, the members of nested structures are named `<member>.<variable>`
+    pub(crate) fn var(&self) -> proc_macro2::TokenStream {
+        let path = self.name.split('.').map(field);
+        quote!(#(#path).*)
+    }
+    // Rust identifier of the variable methods, e.g. `bus_signal` for `bus.signal`,
+    // prefixed with `n_` if it starts with a digit
+    pub(crate) fn method(&self) -> Ident {
+        let method = self.name.replace('.', "_");
+        if method.starts_with(|c: char| c.is_ascii_digit()) {
+            format_ident!("n_{}", method)
+        } else {
+            Ident::new(&method, Span::call_site())
+        }
+    }
+    // Rust identifier of the output getter, the snake case method, a raw identifier
+    // for the Rust keywords, e.g. `r#type`, or suffixed with `_` for the keywords
+    // that can't be raw identifiers, e.g. `self_`
+    fn getter(&self) -> Ident {
+        let mut getter = String::new();
+        let mut prev = '_';
+        for c in self.method().to_string().chars() {
+            if c.is_uppercase() && prev != '_' {
+                getter.push('_');
+            }
+            getter.push(c);
+            prev = c;
+        }
+        let getter = getter.to_lowercase();
+        match getter.as_str() {
+            _ if syn::parse_str::<Ident>(&getter).is_ok() => Ident::new(&getter, Span::call_site()),
+            "self" | "super" | "crate" | "_" => format_ident!("{}_", getter),
+            _ => Ident::new_raw(&getter, Span::call_site()),
+        }
+    }
+    // Rust type of the array elements along the outermost dimension
+    fn elem_ty(&self) -> proc_macro2::TokenStream {
+        self.size
+            .iter()
+            .skip(1)
+            .rev()
+            .fold(self.ty.ty(), |elem, size| quote!([#elem; #size]))
+    }
+    // Array variable
+    pub(crate) fn is_array(&self) -> bool {
+        !self.size.is_empty()
+    }
+    // Number of scalar elements
+    pub(crate) fn len(&self) -> usize {
+        self.size.iter().product()
+    }
+    // Dimensions description, e.g. `6x12`
+    pub(crate) fn dims(&self) -> String {
+        if self.is_array() {
+            let dims: Vec<_> = self.size.iter().map(|n| n.to_string()).collect();
+            dims.join("x")
+        } else {
+            "1".to_string()
+        }
+    }
+    // Nested array type of `elem`, e.g. `[[f64; 12]; 6]`
+    pub(crate) fn array_ty(&self, elem: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        self.size
+            .iter()
+            .rev()
+            .fold(elem, |elem, size| quote!([#elem; #size]))
+    }
+    // Index of each scalar element in row-major order, e.g. `[i][j]`, empty for scalars
+    fn indices(&self) -> Vec<Vec<usize>> {
+        self.size.iter().fold(vec![vec![]], |indices, &size| {
+            indices
+                .into_iter()
+                .flat_map(|index| {
+                    (0..size).map(move |i| {
+                        let mut index = index.clone();
+                        index.push(i);
+                        index
+                    })
+                })
+                .collect()
+        })
+    }
+    // Iterator over the scalar elements values of the variable in `src`
+    fn values(&self, src: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let var = self.var();
+        if self.is_array() {
+            let flatten = (1..self.size.len()).map(|_| quote!(.flatten()));
+            quote!(#src.#var.iter() #(#flatten)*.copied())
+        } else {
+            quote!(core::iter::once(#src.#var))
+        }
+    }
+    // The variables of the structures `a` and `b` are equal, the floats within a relative
+    // tolerance if `approx`
+    fn eq(
+        &self,
+        a: &proc_macro2::TokenStream,
+        b: &proc_macro2::TokenStream,
+        approx: bool,
+    ) -> proc_macro2::TokenStream {
+        if approx && self.ty.is_float() {
+            let tol = match self.ty {
+                Primitive::F32 => quote!(1e-5f32),
+                _ => quote!(1e-9f64),
+            };
+            let (a, b) = (self.values(a), self.values(b));
+            quote! {
+                #a.zip(#b).all(|(a, b)| (a - b).abs() <= #tol * a.abs().max(b.abs()).max(1.))
+            }
+        } else {
+            let var = self.var();
+            quote!(#a.#var == #b.#var)
+        }
+    }
+    // The variables of the structures `a` and `b` are equal, the floats within `epsilon`
+    // as by `approx::AbsDiffEq` of `f64`
+    fn abs_diff_eq(
+        &self,
+        a: &proc_macro2::TokenStream,
+        b: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        if self.ty.is_float() {
+            let (a, b) = (self.values(a), self.values(b));
+            quote! {
+                #a.zip(#b).all(|(a, b)| {
+                    ::simulink_rs::approx::AbsDiffEq::abs_diff_eq(
+                        &f64::from(a),
+                        &f64::from(b),
+                        epsilon,
+                    )
+                })
+            }
+        } else {
+            let var = self.var();
+            quote!(#a.#var == #b.#var)
+        }
+    }
+    // Nested array literal of the row-major scalar elements `values`
+    pub(crate) fn nest(&self, values: Vec<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+        self.size
+            .iter()
+            .rev()
+            .fold(values, |values, &size| {
+                values
+                    .chunks(size)
+                    .map(|chunk| quote!([#(#chunk),*]))
+                    .collect()
+            })
+            .into_iter()
+            .collect()
+    }
+}
+// Member of a Simulink structure, a variable or a nested structure with its type and members
+pub(crate) enum Member<'a> {
+    Variable(Ident, &'a IO),
+    Struct(Ident, String, Vec<Member<'a>>),
+}
+
+// Structure variables, whether the structure has pointer members
+// and the other member declarations that aren't parsed as variables
+#[derive(Debug, Default)]
+pub(crate) struct List(pub(crate) Vec<IO>, pub(crate) bool, pub(crate) Vec<String>);
+impl List {
+    // The structure has pointer members (not parsed as variables)
+    pub(crate) fn has_pointers(&self) -> bool {
+        self.1
+    }
+    // The first two variables sanitized into the same Rust field or accessor identifier,
+    // with the identifier
+    pub(crate) fn collision(&self) -> Option<(&str, &str, String)> {
+        let (mut fields, mut accessors) = (HashMap::new(), HashMap::new());
+        self.0.iter().find_map(|io| {
+            let (field, accessor) = (io.var().to_string(), io.getter().to_string());
+            let name = io.name.as_str();
+            match (
+                fields.insert(field.clone(), name),
+                accessors.insert(accessor.clone(), name),
+            ) {
+                (Some(other), _) => Some((other, name, field)),
+                (None, Some(other)) => Some((other, name, accessor)),
+                (None, None) => None,
+            }
+        })
+    }
+    // Null pointer members for the structure literals
+    pub(crate) fn null_pointers(&self) -> Option<proc_macro2::TokenStream> {
+        self.has_pointers()
+            .then(|| quote!(..unsafe { core::mem::zeroed() }))
+    }
+    // Compile time assertions that the variables of the Simulink structure `ty` are in the
+    // memory order of its fields and, without pointer or nested structures members,
+    // that no field is missing,
+    // and warnings for the member declarations that aren't parsed
+    pub(crate) fn layout_assertions(
+        &self,
+        ty: proc_macro2::TokenStream,
+        c_type: &str,
+    ) -> proc_macro2::TokenStream {
+        // the tail padding of the nested structures isn't accounted for
+        let contiguous = !self.has_pointers() && !self.is_nested();
+        let fields = self.0.iter().map(|io| {
+            let var = io.var();
+            let field_ty = io.array_ty(io.ty.ty());
+            let order = format!("{}::{} isn't in the parsed fields order", c_type, io.name);
+            let missing = format!(
+                "{} has a field missing or out of order before {}",
+                c_type, io.name
+            );
+            let contiguity = contiguous.then(|| {
+                quote! {
+                    assert!(
+                        offset == end.next_multiple_of(core::mem::align_of::<#field_ty>()),
+                        #missing
+                    );
+                }
+            });
+            quote! {
+                let offset = core::mem::offset_of!(#ty, #var);
+                assert!(offset >= end, #order);
+                #contiguity
+                end = offset + core::mem::size_of::<#field_ty>();
+            }
+        });
+        let size = contiguous.then(|| {
+            let missing = format!("{} has a field missing at the end", c_type);
+            quote! {
+                assert!(
+                    core::mem::size_of::<#ty>() == end.next_multiple_of(core::mem::align_of::<#ty>()),
+                    #missing
+                );
+            }
+        });
+        let warnings = self.2.iter().map(|member| {
+            let note = format!("the member `{}` of {} isn't parsed", member, c_type);
+            quote! {
+                const _: () = {
+                    #[deprecated(note = #note)]
+                    const UNPARSED_MEMBER: () = ();
+                    UNPARSED_MEMBER
+                };
+            }
+        });
+        quote! {
+            #[allow(unused_assignments)]
+            const _: () = {
+                let mut end = 0usize;
+                #(#fields)*
+                #size
+            };
+            #(#warnings)*
+        }
+    }
+    // Assertions that the `Default` of the Simulink structure `ty` has the same bytes as the
+    // all-zero C structure, variable by variable, leaving out the padding
+    pub(crate) fn zeroed_assertions(
+        &self,
+        ty: proc_macro2::TokenStream,
+        c_type: &str,
+    ) -> proc_macro2::TokenStream {
+        let fields = self.0.iter().map(|io| {
+            let var = io.var();
+            let field_ty = io.array_ty(io.ty.ty());
+            let diverged = format!("the default of {}::{} isn't all zeros", c_type, io.name);
+            quote! {
+                let bytes = |value: &#ty| unsafe {
+                    core::slice::from_raw_parts(
+                        (&value.#var as *const #field_ty).cast::<u8>(),
+                        core::mem::size_of::<#field_ty>(),
+                    )
+                };
+                assert!(bytes(&default) == bytes(&zeroed), #diverged);
+            }
+        });
+        quote! {
+            {
+                let default = <#ty as Default>::default();
+                let zeroed: #ty = unsafe { core::mem::zeroed() };
+                #(#fields)*
+            }
+        }
+    }
+    // Resolves the Rust types of the variables, defaults to f64,
+    // returns the `name: ctype` of the variables of unrecognized types
+    pub(crate) fn resolve(&mut self, types: &TypeMap) -> Vec<String> {
+        let mut unrecognized = vec![];
+        for io in self.0.iter_mut() {
+            io.ty = types.primitive(&io.ctype).unwrap_or_else(|| {
+                unrecognized.push(format!("{}: {}", io.name, io.ctype));
+                Primitive::default()
+            });
+        }
+        unrecognized
+    }
+    // Assignments of the variables in `src` to the variables with the same name, size and type in `self`
+    pub(crate) fn connect(
+        &self,
+        dst: proc_macro2::TokenStream,
+        src_list: &List,
+        src: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        self.0
+            .iter()
+            .filter(|io| {
+                src_list.0.iter().any(|src_io| {
+                    src_io.name == io.name && src_io.size == io.size && src_io.ty == io.ty
+                })
+            })
+            .fold(proc_macro2::TokenStream::default(), |t, io| {
+                diagnostic!("|  - {}", io.name);
+                let var = io.var();
+                quote! {
+                    #t
+                    #dst.#var = #src.#var;
+                }
+            })
+    }
+    // Running minimum and maximum of the variables in `src`
+    // updating the `range` array of (min,max) tuples
+    pub(crate) fn range(
+        &self,
+        range: proc_macro2::TokenStream,
+        src: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        self.0
+            .iter()
+            .enumerate()
+            .fold(proc_macro2::TokenStream::default(), |t, (i, io)| {
+                let value = io.ty.to_f64(quote!(value));
+                let values = io.values(&src);
+                let values = quote!(#values.map(|value| #value));
+                quote! {
+                    #t
+                    for value in #values {
+                        #range[#i] = (#range[#i].0.min(value), #range[#i].1.max(value));
+                    }
+                }
+            })
+    }
+    // Layout description of the variables, used for the fingerprint
+    pub(crate) fn layout(&self) -> String {
+        self.0
+            .iter()
+            .map(|io| format!("{}:{}:{};", io.name, io.ctype, io.dims()))
+            .collect()
+    }
+    // Little-endian bytes of the variables in `src` appended to the `bytes` vector
+    pub(crate) fn to_le_bytes(
+        &self,
+        bytes: proc_macro2::TokenStream,
+        src: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        self.0
+            .iter()
+            .fold(proc_macro2::TokenStream::default(), |t, io| {
+                let var = io.var();
+                if io.is_array() {
+                    let (value, values) = (io.ty.to_le_bytes(quote!(value)), io.values(&src));
+                    quote! {
+                        #t
+                        for value in #values {
+                            #bytes.extend_from_slice(&#value);
+                        }
+                    }
+                } else {
+                    let value = io.ty.to_le_bytes(quote!(#src.#var));
+                    quote! {
+                        #t
+                        #bytes.extend_from_slice(&#value);
+                    }
+                }
+            })
+    }
+    // JSON array of the variables
+    pub(crate) fn json(&self) -> String {
+        let variables: Vec<_> = self
+            .0
+            .iter()
+            .map(|io| {
+                format!(
+                    "\n    {{ \"name\": {}, \"type\": {}, \"size\": {}, \"dims\": {:?} }}",
+                    json_string(&io.name),
+                    json_string(&io.ctype),
+                    io.len(),
+                    io.size
+                )
+            })
+            .collect();
+        if variables.is_empty() {
+            "[]".to_string()
+        } else {
+            format!("[{}\n  ]", variables.join(","))
+        }
+    }
+    // Variables in `dst` read from the little-endian `bytes` slice, advancing the slice
+    pub(crate) fn read_le_bytes(
+        &self,
+        bytes: proc_macro2::TokenStream,
+        dst: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        self.elements(dst).into_iter().fold(
+            proc_macro2::TokenStream::default(),
+            |t, (place, ty)| {
+                let (rty, value) = (ty.ty(), ty.read_le_bytes(quote!(value)));
+                quote! {
+                    #t
+                    let (value, rest) = #bytes.split_at(core::mem::size_of::<#rty>());
+                    #place = #value;
+                    #bytes = rest;
+                }
+            },
+        )
+    }
+    // Number of bytes of the variables
+    pub(crate) fn byte_size(&self) -> proc_macro2::TokenStream {
+        let sizes = self.0.iter().map(|io| {
+            let (ty, size) = (io.ty.ty(), io.len());
+            quote!(+ #size * core::mem::size_of::<#ty>())
+        });
+        quote!(0 #(#sizes)*)
+    }
+    // Names of the scalar elements, arrays elements are named `name[i]` or `name[i][j]`
+    pub(crate) fn names(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .flat_map(|io| {
+                io.indices().into_iter().map(move |index| {
+                    index
+                        .iter()
+                        .fold(io.name.clone(), |name, i| format!("{}[{}]", name, i))
+                })
+            })
+            .collect()
+    }
+    // Upper camel case enumeration variants of the variables, e.g. `BusSignal` for `bus.signal`,
+    // prefixed with `N` if they start with a digit and suffixed with `_` until they are unique
+    fn variants(&self) -> Vec<Ident> {
+        let mut variants: Vec<String> = vec![];
+        for io in &self.0 {
+            let mut variant: String = io
+                .name
+                .split(['_', '.'])
+                .flat_map(|part| {
+                    let mut chars = part.chars();
+                    chars
+                        .next()
+                        .map(|c| c.to_ascii_uppercase())
+                        .into_iter()
+                        .chain(chars)
+                })
+                .collect();
+            if variant.starts_with(|c: char| c.is_ascii_digit()) {
+                variant.insert(0, 'N');
+            }
+            while variant == "Self" || variants.contains(&variant) {
+                variant.push('_');
+            }
+            variants.push(variant);
+        }
+        variants
+            .iter()
+            .map(|variant| Ident::new(variant, Span::call_site()))
+            .collect()
+    }
+    // The enumerations `scalar` and `array` of the scalar and of the array variables of the
+    // Simulink structure `ty`, with `Index` and `IndexMut` of the variable value, or of the
+    // slice of the array elements in row-major order, if the variables have the same type
+    pub(crate) fn signal_index(
+        &self,
+        ty: proc_macro2::TokenStream,
+        scalar: proc_macro2::TokenStream,
+        array: proc_macro2::TokenStream,
+        vis: &syn::Visibility,
+    ) -> proc_macro2::TokenStream {
+        let variants = self.variants();
+        let (arrays, scalars): (Vec<_>, Vec<_>) = self
+            .0
+            .iter()
+            .zip(variants)
+            .partition(|(io, _)| io.is_array());
+        [(scalars, scalar, false), (arrays, array, true)]
+            .into_iter()
+            .filter(|(ios, ..)| !ios.is_empty())
+            .map(|(ios, signal, is_array)| {
+                let n = ios.len();
+                let (names, variants): (Vec<_>, Vec<_>) =
+                    ios.iter().map(|(io, variant)| (&io.name, variant)).unzip();
+                let docs = names.iter().map(|name| format!("`{}`", name));
+                let elem = ios[0].0.ty;
+                let index = ios.iter().all(|(io, _)| io.ty == elem).then(|| {
+                    let elem = elem.ty();
+                    let (output, index, index_mut): (_, Vec<_>, Vec<_>) = if is_array {
+                        let flatten = |io: &IO, method: Ident| {
+                            let var = io.var();
+                            let calls = (1..io.size.len()).map(|_| &method);
+                            quote!(self.#var #(.#calls())*)
+                        };
+                        let (index, index_mut) = ios
+                            .iter()
+                            .map(|(io, _)| {
+                                let (a, b) = (
+                                    flatten(io, format_ident!("as_flattened")),
+                                    flatten(io, format_ident!("as_flattened_mut")),
+                                );
+                                (quote!(&#a[..]), quote!(&mut #b[..]))
+                            })
+                            .unzip();
+                        (quote!([#elem]), index, index_mut)
+                    } else {
+                        let (index, index_mut) = ios
+                            .iter()
+                            .map(|(io, _)| {
+                                let var = io.var();
+                                (quote!(&self.#var), quote!(&mut self.#var))
+                            })
+                            .unzip();
+                        (elem, index, index_mut)
+                    };
+                    quote! {
+                        impl core::ops::Index<#signal> for #ty {
+                            type Output = #output;
+                            fn index(&self, signal: #signal) -> &Self::Output {
+                                match signal {
+                                    #(#signal::#variants => #index,)*
+                                }
+                            }
+                        }
+                        impl core::ops::IndexMut<#signal> for #ty {
+                            fn index_mut(&mut self, signal: #signal) -> &mut Self::Output {
+                                match signal {
+                                    #(#signal::#variants => #index_mut,)*
+                                }
+                            }
+                        }
+                    }
+                });
+                quote! {
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                    #vis enum #signal {
+                        #(#[doc = #docs] #variants,)*
+                    }
+                    impl #signal {
+                        /// The variants in the order of the Simulink structure
+                        pub const ALL: [Self; #n] = [#(Self::#variants),*];
+                        /// Returns the Simulink name of the variable
+                        pub fn name(&self) -> &'static str {
+                            match self {
+                                #(Self::#variants => #names,)*
+                            }
+                        }
+                    }
+                    #index
+                }
+            })
+            .collect()
+    }
+    // The names and the numbers of elements of the variables, 1 for scalars and
+    // the product of the dimensions for arrays
+    pub(crate) fn layout_table(&self) -> proc_macro2::TokenStream {
+        let (names, lens): (Vec<_>, Vec<_>) = self.0.iter().map(|io| (&io.name, io.len())).unzip();
+        quote!(&[#((#names, #lens)),*])
+    }
+    // Scalar elements of the variables in `src` with their types, arrays are expanded in order
+    pub(crate) fn elements(
+        &self,
+        src: proc_macro2::TokenStream,
+    ) -> Vec<(proc_macro2::TokenStream, Primitive)> {
+        self.0
+            .iter()
+            .flat_map(|io| {
+                let var = io.var();
+                io.indices()
+                    .into_iter()
+                    .map(|index| (quote!(#src.#var #([#index])*), io.ty))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+    // Names of the variables that differ by more than `tolerance` between `a` and `b`
+    // pushed into `diff`
+    pub(crate) fn diff(
+        &self,
+        diff: proc_macro2::TokenStream,
+        a: proc_macro2::TokenStream,
+        b: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        self.0
+            .iter()
+            .fold(proc_macro2::TokenStream::default(), |t, io| {
+                let var = io.var();
+                let name = &io.name;
+                let (a_value, b_value) = (io.ty.to_f64(quote!(a)), io.ty.to_f64(quote!(b)));
+                let check = if io.is_array() {
+                    let (a_values, b_values) = (io.values(&a), io.values(&b));
+                    quote! {
+                        #a_values
+                            .zip(#b_values)
+                            .any(|(a, b)| !((#a_value - #b_value).abs() <= tolerance))
+                    }
+                } else {
+                    quote! {{
+                        let (a, b) = (#a.#var, #b.#var);
+                        !((#a_value - #b_value).abs() <= tolerance)
+                    }}
+                };
+                quote! {
+                    #t
+                    if #check {
+                        #diff.push(#name);
+                    }
+                }
+            })
+    }
+    // Floating point inputs setters zeroing the values within a dead-band
+    pub(crate) fn deadband(&self) -> proc_macro2::TokenStream {
+        self.0.iter().filter(|io| io.ty.is_float()).fold(
+            proc_macro2::TokenStream::default(),
+            |t, io| {
+                let (var, method) = (io.var(), io.method());
+                let doc = format!(
+                    " Sets the input `{}` to `v`, values within `[-band,band]` are set to zero",
+                    io.name
+                );
+                let (ty, zero) = (io.ty.ty(), io.ty.zero());
+                let (arg, value) = (
+                    io.array_ty(ty.clone()),
+                    io.size.iter().fold(
+                        quote!(if v.abs() <= band { #zero } else { v }),
+                        |value, _| quote!(v.map(|v| #value)),
+                    ),
+                );
+                quote! {
+                    #t
+                    #[doc = #doc]
+                    pub fn [<set_ #method:snake _deadband>](&mut self, v: #arg, band: #ty) {
+                        self.inputs.#var = #value;
+                    }
+                }
+            },
+        )
+    }
+    // Outputs getters, the arrays are returned by reference and implement `simulink_rs::AsVector`,
+    // and their elements, along the outermost dimension, are returned by `<name>_at`
+    pub(crate) fn getters(&self) -> proc_macro2::TokenStream {
+        self.0
+            .iter()
+            .fold(proc_macro2::TokenStream::default(), |t, io| {
+                let (var, getter) = (io.var(), io.getter());
+                let doc = format!(" Returns the output `{}`", io.name);
+                let ty = io.array_ty(io.ty.ty());
+                if io.is_array() {
+                    let getter_at = format_ident!("{}_at", getter);
+                    let doc_at = format!(
+                        " Returns the element `i` of the output `{}`, `None` if `i` isn't less than {}",
+                        io.name, io.size[0]
+                    );
+                    let elem = io.elem_ty();
+                    quote! {
+                        #t
+                        #[doc = #doc]
+                        pub fn #getter(&self) -> &#ty {
+                            &self.outputs.#var
+                        }
+                        #[doc = #doc_at]
+                        pub fn #getter_at(&self, i: usize) -> Option<#elem> {
+                            self.outputs.#var.get(i).copied()
+                        }
+                    }
+                } else {
+                    quote! {
+                        #t
+                        #[doc = #doc]
+                        pub fn #getter(&self) -> #ty {
+                            self.outputs.#var
+                        }
+                    }
+                }
+            })
+    }
+    // Inputs setters, the arrays are set from slices of the same length or else return an error,
+    // and their elements, along the outermost dimension, are set by `set_<name>_at`
+    pub(crate) fn setters(&self) -> proc_macro2::TokenStream {
+        self.0
+            .iter()
+            .fold(proc_macro2::TokenStream::default(), |t, io| {
+                let (var, method) = (io.var(), io.method());
+                if io.is_array() {
+                    let doc = format!(
+                        " Sets the input `{}`\n\n # Errors\n\n Returns [SimulinkError::WrongLength](::simulink_rs::SimulinkError::WrongLength) if `v` length is not {}",
+                        io.name, io.size[0]
+                    );
+                    let doc_at = format!(
+                        " Sets the element `i` of the input `{}`\n\n # Errors\n\n Returns [SimulinkError::IndexOutOfBounds](::simulink_rs::SimulinkError::IndexOutOfBounds) if `i` isn't less than {}",
+                        io.name, io.size[0]
+                    );
+                    let (name, len) = (&io.name, io.size[0]);
+                    let ty = io.elem_ty();
+                    quote! {
+                        #t
+                        #[doc = #doc]
+                        pub fn [<set_ #method:snake>](&mut self, v: &[#ty]) -> Result<(), ::simulink_rs::SimulinkError> {
+                            if v.len() != #len {
+                                return Err(::simulink_rs::SimulinkError::WrongLength {
+                                    field: #name,
+                                    expected: #len,
+                                    got: v.len(),
+                                });
+                            }
+                            self.inputs.#var.copy_from_slice(v);
+                            Ok(())
+                        }
+                        #[doc = #doc_at]
+                        pub fn [<set_ #method:snake _at>](&mut self, i: usize, v: #ty) -> Result<(), ::simulink_rs::SimulinkError> {
+                            if i >= #len {
+                                return Err(::simulink_rs::SimulinkError::IndexOutOfBounds {
+                                    field: #name,
+                                    index: i,
+                                    len: #len,
+                                });
+                            }
+                            self.inputs.#var[i] = v;
+                            Ok(())
+                        }
+                    }
+                } else {
+                    let doc = format!(" Sets the input `{}`", io.name);
+                    let ty = io.ty.ty();
+                    quote! {
+                        #t
+                        #[doc = #doc]
+                        pub fn [<set_ #method:snake>](&mut self, v: #ty) {
+                            self.inputs.#var = v;
+                        }
+                    }
+                }
+            })
+    }
+    // Inputs builder setters, the arrays are set from arrays of the same size
+    pub(crate) fn builder_setters(&self) -> proc_macro2::TokenStream {
+        self.0
+            .iter()
+            .map(|io| {
+                let (var, method) = (io.var(), io.getter());
+                let doc = format!(" Sets the input `{}`", io.name);
+                let ty = io.array_ty(io.ty.ty());
+                quote! {
+                    #[doc = #doc]
+                    pub fn #method(mut self, v: #ty) -> Self {
+                        self.inputs.#var = v;
+                        self
+                    }
+                }
+            })
+            .collect()
+    }
+    // `nalgebra` views of the 1-D and 2-D `place` (input or output) arrays in `src`,
+    // `<name>_vector` and `<name>_matrix`, or mutable views named `<name>_vector_mut`
+    // and `<name>_matrix_mut`
+    //
+    // The 2-D arrays are row-major, the matrix views have the row and column strides of the arrays
+    pub(crate) fn views(
+        &self,
+        src: proc_macro2::TokenStream,
+        place: &str,
+        mutable: bool,
+    ) -> proc_macro2::TokenStream {
+        let na = quote!(::simulink_rs::nalgebra);
+        let (receiver, borrow, flattened, view, suffix, kind) = if mutable {
+            (
+                quote!(&mut self),
+                quote!(&mut),
+                quote!(as_flattened_mut),
+                quote!(MatrixViewMut),
+                "_mut",
+                "mutable `nalgebra` view of the",
+            )
+        } else {
+            (
+                quote!(&self),
+                quote!(&),
+                quote!(as_flattened),
+                quote!(MatrixView),
+                "",
+                "`nalgebra` view of the",
+            )
+        };
+        self.0
+            .iter()
+            .fold(proc_macro2::TokenStream::default(), |t, io| {
+                let (var, method) = (io.var(), io.method());
+                let ty = io.ty.ty();
+                match io.size[..] {
+                    [n] => {
+                        let doc = format!(" Returns the {} {} `{}`", kind, place, io.name);
+                        let name = format_ident!("{}_vector{}", method, suffix);
+                        quote! {
+                            #t
+                            #[doc = #doc]
+                            pub fn [<#name:snake>](#receiver) -> #na::#view<'_, #ty, #na::Const<#n>, #na::U1> {
+                                #na::#view::from_slice_generic(#borrow #src.#var, #na::Const::<#n>, #na::U1)
+                            }
+                        }
+                    }
+                    [rows, cols] => {
+                        let doc = format!(" Returns the {} {} `{}`", kind, place, io.name);
+                        let name = format_ident!("{}_matrix{}", method, suffix);
+                        quote! {
+                            #t
+                            #[doc = #doc]
+                            pub fn [<#name:snake>](#receiver) -> #na::#view<'_, #ty, #na::Const<#rows>, #na::Const<#cols>, #na::Const<#cols>, #na::U1> {
+                                #na::#view::from_slice_with_strides_generic(
+                                    #src.#var.#flattened(),
+                                    #na::Const::<#rows>,
+                                    #na::Const::<#cols>,
+                                    #na::Const::<#cols>,
+                                    #na::U1,
+                                )
+                            }
+                        }
+                    }
+                    _ => t,
+                }
+            })
+    }
+    // Match arms of the variables names to the index of the variables
+    pub(crate) fn match_index(
+        &self,
+        f: impl Fn(usize) -> proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        self.0
+            .iter()
+            .enumerate()
+            .fold(proc_macro2::TokenStream::default(), |t, (i, io)| {
+                let name = &io.name;
+                let value = f(i);
+                quote! {
+                    #t
+                    #name => #value,
+                }
+            })
+    }
+    // The variables grouped by the nested structures members of the structure `ty`
+    fn tree(&self, ty: &str) -> Vec<Member<'_>> {
+        fn group<'a>(ios: &[&'a IO], depth: usize, ty: &str) -> Vec<Member<'a>> {
+            let mut members = vec![];
+            let mut rest = ios;
+            while let Some(io) = rest.first() {
+                let path: Vec<_> = io.name.split('.').collect();
+                let name = field(path[depth]);
+                if path.len() == depth + 1 {
+                    members.push(Member::Variable(name, io));
+                    rest = &rest[1..];
+                } else {
+                    let n = rest
+                        .iter()
+                        .take_while(|other| other.name.split('.').nth(depth) == Some(path[depth]))
+                        .count();
+                    let member_ty = match &io.structs[depth] {
+                        anonymous if anonymous.starts_with("__bindgen_ty_") => {
+                            format!("{}{}", ty, anonymous)
+                        }
+                        tag => tag.clone(),
+                    };
+                    let nested = group(&rest[..n], depth + 1, &member_ty);
+                    members.push(Member::Struct(name, member_ty, nested));
+                    rest = &rest[n..];
+                }
+            }
+            members
+        }
+        let ios: Vec<_> = self.0.iter().collect();
+        group(&ios, 0, ty)
+    }
+    // Zero literals of the variables of the structure `ty`, or else the literals
+    // of the row-major scalar elements `values` of the variables in order
+    pub(crate) fn quote(&self, ty: &str, values: Option<&[f64]>) -> proc_macro2::TokenStream {
+        fn fields<'a>(
+            members: &[Member],
+            values: &mut Option<impl Iterator<Item = &'a f64>>,
+        ) -> proc_macro2::TokenStream {
+            members.iter().fold(
+                proc_macro2::TokenStream::default(),
+                |t, member| match member {
+                    Member::Variable(var, io) => {
+                        let value = match values {
+                            Some(values) => {
+                                io.nest(values.take(io.len()).map(|v| io.ty.literal(*v)).collect())
+                            }
+                            None => io
+                                .size
+                                .iter()
+                                .rev()
+                                .fold(io.ty.zero(), |zero, size| quote!([#zero;#size])),
+                        };
+                        quote! {
+                            #t
+                            #var: #value,
+                        }
+                    }
+                    Member::Struct(var, ty, members) => {
+                        let ty = Ident::new(ty, Span::call_site());
+                        let fields = fields(members, values);
+                        quote! {
+                            #t
+                            #var: #ty { #fields },
+                        }
+                    }
+                },
+            )
+        }
+        fields(&self.tree(ty), &mut values.map(|values| values.iter()))
+    }
+    // The structure has nested structures members
+    fn is_nested(&self) -> bool {
+        self.0.iter().any(|io| !io.structs.is_empty())
+    }
+    // `PartialEq` comparing the variables of the Simulink structure `ty`, arrays element-wise
+    pub(crate) fn partial_eq(&self, ty: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let fields = self
+            .0
+            .iter()
+            .map(|io| io.eq(&quote!(self), &quote!(other), false));
+        quote! {
+            impl PartialEq for #ty {
+                fn eq(&self, other: &Self) -> bool {
+                    true #(&& #fields)*
+                }
+            }
+        }
+    }
+    // `approx_eq` method of the Simulink structure `ty` comparing the variables as `partial_eq`
+    // but the floats within a tolerance relative to the largest magnitude, `1e-9` for `f64`
+    // and `1e-5` for `f32`
+    pub(crate) fn approx_eq(&self, ty: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let fields = self
+            .0
+            .iter()
+            .map(|io| io.eq(&quote!(self), &quote!(other), true));
+        quote! {
+            impl #ty {
+                /// Compares the variables, arrays element-wise, the floats within a tolerance
+                /// relative to their magnitude, `1e-9` for `f64` and `1e-5` for `f32`
+                pub fn approx_eq(&self, other: &Self) -> bool {
+                    true #(&& #fields)*
+                }
+            }
+        }
+    }
+    // `approx::AbsDiffEq` of the Simulink structure `ty` with a `f64` epsilon, the integer and
+    // boolean variables are compared exactly
+    pub(crate) fn abs_diff_eq(&self, ty: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let fields = self
+            .0
+            .iter()
+            .map(|io| io.abs_diff_eq(&quote!(self), &quote!(other)));
+        quote! {
+            impl ::simulink_rs::approx::AbsDiffEq for #ty {
+                type Epsilon = f64;
+                fn default_epsilon() -> f64 {
+                    f64::EPSILON
+                }
+                fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                    true #(&& #fields)*
+                }
+            }
+        }
+    }
+    // The name of the first variable that differs between the structures `a` and `b`,
+    // compared as by `partial_eq`, or as by `approx_eq` if `approx`, or `None`
+    pub(crate) fn first_diff(
+        &self,
+        a: &proc_macro2::TokenStream,
+        b: &proc_macro2::TokenStream,
+        approx: bool,
+    ) -> proc_macro2::TokenStream {
+        let (names, fields): (Vec<_>, Vec<_>) = self
+            .0
+            .iter()
+            .map(|io| (&io.name, io.eq(a, b, approx)))
+            .unzip();
+        quote!(#(if !(#fields) { Some(#names) } else)* { None })
+    }
+    // `serde` implementations of the Simulink structure `ty`, derived from a remote definition
+    // with the arrays longer than 32 elements serialized with `serde_big_array`
+    pub(crate) fn serde(&self, ty: &str) -> proc_macro2::TokenStream {
+        if !cfg!(feature = "serde") {
+            return quote!();
+        }
+        // the remote definitions of the nested structures and the (de)serialization
+        // functions of the big arrays are appended to `remotes`
+        fn fields(
+            members: &[Member],
+            remotes: &mut Vec<proc_macro2::TokenStream>,
+        ) -> proc_macro2::TokenStream {
+            members.iter().fold(
+                proc_macro2::TokenStream::default(),
+                |t, member| match member {
+                    Member::Variable(var, io) => {
+                        let field_ty = io.array_ty(io.ty.ty());
+                        let big_array = match io.size.as_slice() {
+                            [n] if *n > 32 => quote!(
+                                #[serde(with = "::simulink_rs::serde_big_array::BigArray")]
+                            ),
+                            size if size.iter().any(|&n| n > 32) => {
+                                // the nested arrays are wrapped in `serde_big_array::Array`
+                                // at each dimension, from the innermost one outward
+                                let ty = io.ty.ty();
+                                let ty = quote!(#ty);
+                                let array = quote!(::simulink_rs::serde_big_array::Array);
+                                let (mut raw_ty, mut wrapped_ty) = (ty.clone(), ty);
+                                let (mut wrap, mut unwrap) = (quote!(#array), quote!());
+                                for (i, n) in size.iter().enumerate().rev() {
+                                    let inner_raw = raw_ty.clone();
+                                    raw_ty = quote!([#inner_raw; #n]);
+                                    wrapped_ty = quote!(#array<#wrapped_ty, #n>);
+                                    if i + 1 < size.len() {
+                                        let inner_wrap = wrap;
+                                        wrap = quote!(|value: #raw_ty| #array(value.map(#inner_wrap)));
+                                        let inner_unwrap = unwrap;
+                                        unwrap = quote!(|value: #wrapped_ty| value.0.map(#inner_unwrap));
+                                    } else {
+                                        unwrap = quote!(|value: #wrapped_ty| value.0);
+                                    }
+                                }
+                                let (serialize, deserialize) = (
+                                    format!("serialize_{}", remotes.len()),
+                                    format!("deserialize_{}", remotes.len()),
+                                );
+                                let (serialize_fn, deserialize_fn) = (
+                                    Ident::new(&serialize, Span::call_site()),
+                                    Ident::new(&deserialize, Span::call_site()),
+                                );
+                                remotes.push(quote! {
+                                    fn #serialize_fn<S: ::simulink_rs::serde::Serializer>(
+                                        value: &#field_ty,
+                                        serializer: S,
+                                    ) -> Result<S::Ok, S::Error> {
+                                        let value: #wrapped_ty = (#wrap)(*value);
+                                        ::simulink_rs::serde::Serialize::serialize(&value, serializer)
+                                    }
+                                    fn #deserialize_fn<'de, D: ::simulink_rs::serde::Deserializer<'de>>(
+                                        deserializer: D,
+                                    ) -> Result<#field_ty, D::Error> {
+                                        let value: #wrapped_ty =
+                                            ::simulink_rs::serde::Deserialize::deserialize(deserializer)?;
+                                        Ok((#unwrap)(value))
+                                    }
+                                });
+                                quote!(#[serde(serialize_with = #serialize, deserialize_with = #deserialize)])
+                            }
+                            _ => quote!(),
+                        };
+                        quote! {
+                            #t
+                            #big_array
+                            #var: #field_ty,
+                        }
+                    }
+                    Member::Struct(var, ty, members) => {
+                        let remote = format!("Remote{}", ty);
+                        let (c_type, remote_ty) = (
+                            Ident::new(ty, Span::call_site()),
+                            Ident::new(&remote, Span::call_site()),
+                        );
+                        let nested = fields(members, remotes);
+                        remotes.push(quote! {
+                            #[allow(non_camel_case_types)]
+                            #[derive(::simulink_rs::serde::Serialize, ::simulink_rs::serde::Deserialize)]
+                            #[serde(remote = #ty, crate = "::simulink_rs::serde")]
+                            struct #remote_ty {
+                                #nested
+                            }
+                        });
+                        quote! {
+                            #t
+                            #[serde(with = #remote)]
+                            #var: #c_type,
+                        }
+                    }
+                },
+            )
+        }
+        let mut remotes = vec![];
+        let fields = fields(&self.tree(ty), &mut remotes);
+        let c_type = Ident::new(ty, Span::call_site());
+        quote! {
+            const _: () = {
+                #[derive(::simulink_rs::serde::Serialize, ::simulink_rs::serde::Deserialize)]
+                #[serde(remote = #ty, crate = "::simulink_rs::serde")]
+                struct Remote {
+                    #fields
+                }
+                #(#remotes)*
+                impl ::simulink_rs::serde::Serialize for #c_type {
+                    fn serialize<S: ::simulink_rs::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        Remote::serialize(self, serializer)
+                    }
+                }
+                impl<'de> ::simulink_rs::serde::Deserialize<'de> for #c_type {
+                    fn deserialize<D: ::simulink_rs::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        Remote::deserialize(deserializer)
+                    }
+                }
+            };
+        }
+    }
+}
+
+// Receiver of the wrapper Simulink structures, `inner` is `.0` for the cache line aligned storage
+pub(crate) struct Receiver {
+    this: proc_macro2::TokenStream,
+    inner: proc_macro2::TokenStream,
+}
+impl Receiver {
+    pub(crate) fn new(this: proc_macro2::TokenStream, aligned: bool) -> Self {
+        Self {
+            this,
+            inner: if aligned { quote!(.0) } else { quote!() },
+        }
+    }
+    // The wrapper Simulink structure `field`
+    fn field(&self, field: &str) -> proc_macro2::TokenStream {
+        let Self { this, inner } = self;
+        let field = Ident::new(field, Span::call_site());
+        quote!(#this.#field #inner)
+    }
+    pub(crate) fn inputs(&self) -> proc_macro2::TokenStream {
+        self.field("inputs")
+    }
+    pub(crate) fn outputs(&self) -> proc_macro2::TokenStream {
+        self.field("outputs")
+    }
+    pub(crate) fn states(&self) -> proc_macro2::TokenStream {
+        self.field("states")
+    }
+    // The parameters are never cache line aligned
+    pub(crate) fn parameters(&self) -> proc_macro2::TokenStream {
+        let this = &self.this;
+        quote!(#this.parameters)
+    }
+    // The continuous states are never cache line aligned
+    pub(crate) fn continuous(&self) -> proc_macro2::TokenStream {
+        let this = &self.this;
+        quote!(#this.states_continuous)
+    }
+    // The previous zero-crossings are never cache line aligned
+    pub(crate) fn zero_crossings(&self) -> proc_macro2::TokenStream {
+        let this = &self.this;
+        quote!(#this.zero_crossings)
+    }
+}
+impl quote::ToTokens for Receiver {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.this.to_tokens(tokens)
+    }
+}
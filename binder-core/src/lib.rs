@@ -0,0 +1,142 @@
+//! The Simulink C model parser and the code of the `simulink-binder` macros
+
+// Prints a parsing diagnostic at the macro expansion if the `SIMULINK_BINDER_VERBOSE`
+// environment variable is set
+macro_rules! diagnostic {
+    ($($arg:tt)*) => {
+        if std::env::var_os("SIMULINK_BINDER_VERBOSE").is_some() {
+            println!($($arg)*);
+        }
+    };
+}
+
+mod args;
+mod bindings;
+mod cache;
+mod enums;
+mod features;
+mod feedback;
+mod import;
+mod io;
+mod model;
+mod parse;
+mod rtm;
+mod types;
+mod wrapper;
+
+pub use bindings::import_bindings;
+pub use feedback::feedback;
+pub use import::import;
+
+use io::{List, IO};
+use model::Model;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Simulink C model variable
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable {
+    /// The variable name, `<member>.<variable>` for the members of nested structures
+    pub name: String,
+    /// The variable C type
+    pub ctype: String,
+    /// The variable dimensions, outermost first, empty for scalars
+    pub dims: Vec<usize>,
+}
+impl From<&IO> for Variable {
+    fn from(io: &IO) -> Self {
+        Self {
+            name: io.name.clone(),
+            ctype: io.ctype.clone(),
+            dims: io.size.clone(),
+        }
+    }
+}
+
+/// Simulink C model parsed from the model header
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelInfo {
+    /// The model name, from the `File: <model>.h` header line
+    pub name: String,
+    /// The model version
+    pub version: Option<String>,
+    /// The Simulink Coder version
+    pub coder_version: Option<String>,
+    /// The code generation date
+    pub codegen_date: Option<String>,
+    /// The model solver
+    pub solver: Option<String>,
+    /// The inputs, `None` if the header has no inputs structure
+    pub inputs: Option<Vec<Variable>>,
+    /// The outputs, `None` if the header has no outputs structure
+    pub outputs: Option<Vec<Variable>>,
+    /// The states, `None` if the header has no states structure
+    pub states: Option<Vec<Variable>>,
+    /// The continuous states, `None` if the header has no continuous states structure
+    pub continuous_states: Option<Vec<Variable>>,
+    /// The tunable parameters
+    pub params: Vec<Variable>,
+    /// The model checksum
+    pub checksum: Option<[u32; 4]>,
+    /// The model fundamental sample time in seconds
+    pub sample_time: Option<f64>,
+}
+impl From<Model> for ModelInfo {
+    fn from(model: Model) -> Self {
+        let variables = |list: &List| list.0.iter().map(Variable::from).collect();
+        Self {
+            name: model.name.to_string(),
+            version: model.version,
+            coder_version: model.coder_version,
+            codegen_date: model.codegen_date,
+            solver: model.solver,
+            inputs: model.inputs.as_ref().map(variables),
+            outputs: model.outputs.as_ref().map(variables),
+            states: model.states.as_ref().map(variables),
+            continuous_states: model.continuous.as_ref().map(variables),
+            params: variables(&model.params),
+            checksum: model.checksum,
+            sample_time: model.sample_time,
+        }
+    }
+}
+
+/// Error parsing a Simulink C model header
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError(String);
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// Parses a Simulink C model header
+///
+/// The model is parsed as by the `import!` macro, with the C types aliases, the checksum
+/// and the sample time of the headers and C files in the header directory,
+/// e.g. in a build script `parse_model_header(Path::new("sys/model.h"))`.
+/// If the `OUT_DIR` environment variable is set, the parsed model is cached
+/// in `<OUT_DIR>/<header>_model.cache` until the contents of the headers and C files change,
+/// the cache is shared with the `import!` macro of the crate.
+pub fn parse_model_header(path: &Path) -> std::result::Result<ModelInfo, ParseError> {
+    let out_dir = env::var_os("OUT_DIR").map(PathBuf::from);
+    let sys = path.parent().unwrap_or_else(|| Path::new("."));
+    Model::load(path, sys, None, out_dir.as_deref())
+        .map(ModelInfo::from)
+        .map_err(ParseError)
+}
+
+/// Parses a Simulink C model header, caching the parsed model in `out_dir`
+///
+/// As [parse_model_header] but with the cache written in `<out_dir>/<header>_model.cache`
+/// whatever the `OUT_DIR` environment variable.
+pub fn parse_model_header_cached(
+    path: &Path,
+    out_dir: &Path,
+) -> std::result::Result<ModelInfo, ParseError> {
+    let sys = path.parent().unwrap_or_else(|| Path::new("."));
+    Model::load(path, sys, None, Some(out_dir))
+        .map(ModelInfo::from)
+        .map_err(ParseError)
+}
@@ -0,0 +1,285 @@
+//! The Simulink C model parsed from its header
+
+use crate::io::List;
+use crate::parse::{
+    checksum_string, headers, json_string, parse_checksum, parse_defines, parse_io,
+    parse_params_init, parse_sample_time, strip_comments, structure_lines,
+};
+use crate::types::{Primitive, TypeMap};
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use regex::Regex;
+use std::fs;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+// Simulink model parsed from the C header file
+pub(crate) struct Model {
+    // the model name
+    pub(crate) name: Ident,
+    // the model version
+    pub(crate) version: Option<String>,
+    // the Simulink Coder version, the code generation date and the solver of the header comment
+    pub(crate) coder_version: Option<String>,
+    pub(crate) codegen_date: Option<String>,
+    pub(crate) solver: Option<String>,
+    // the inputs variables, `None` if the header has no inputs structure
+    pub(crate) inputs: Option<List>,
+    // the outputs variables, `None` if the header has no outputs structure
+    pub(crate) outputs: Option<List>,
+    // the states variables, `None` if the header has no states structure
+    pub(crate) states: Option<List>,
+    // the continuous states variables, `None` if the header has no continuous states structure
+    pub(crate) continuous: Option<List>,
+    // the previous zero-crossings signals states, `None` if the model has no zero-crossings
+    pub(crate) zero_crossings: Option<List>,
+    // the tunable parameters
+    pub(crate) params: List,
+    // the values of the parameters initializer of the model data C file
+    pub(crate) params_init: Option<Vec<f64>>,
+    // the model checksum
+    pub(crate) checksum: Option<[u32; 4]>,
+    // the model fundamental sample time in seconds
+    pub(crate) sample_time: Option<f64>,
+    // the Rust type of `real_T`, of the flat conversions of the inputs and outputs
+    pub(crate) precision: Primitive,
+    // the `name: ctype` of the variables of unrecognized types, bound as f64, with their structure
+    pub(crate) unrecognized: Vec<String>,
+}
+impl Model {
+    // Parse the model without the cache
+    pub(crate) fn load_uncached(
+        file_name: &Path,
+        sys: &Path,
+        precision: Option<Primitive>,
+    ) -> std::result::Result<Self, String> {
+        let mut types = TypeMap::default();
+        for file_name in headers(sys) {
+            types.parse(&file_name);
+        }
+        match precision {
+            Some(Primitive::F32) => types.0.insert("real_T".to_string(), "float".to_string()),
+            Some(_) => types.0.insert("real_T".to_string(), "double".to_string()),
+            None => None,
+        };
+        let mut model = Self::parse(file_name)?;
+        model.resolve(&types);
+        model.checksum = parse_checksum(sys);
+        model.sample_time = parse_sample_time(file_name, sys);
+        model.params_init = parse_params_init(&model.name.to_string(), sys);
+        Ok(model)
+    }
+    // Resolves the Rust types of the variables and the precision of `real_T`
+    pub(crate) fn resolve(&mut self, types: &TypeMap) {
+        if types.primitive("real_T") == Some(Primitive::F32) {
+            self.precision = Primitive::F32;
+        }
+        let name = &self.name;
+        let lists = [
+            ("ExtU", self.inputs.as_mut()),
+            ("ExtY", self.outputs.as_mut()),
+            ("DW", self.states.as_mut()),
+            ("X", self.continuous.as_mut()),
+            ("PrevZCX", self.zero_crossings.as_mut()),
+            ("P", Some(&mut self.params)),
+        ];
+        self.unrecognized = lists
+            .into_iter()
+            .filter_map(|(ty, list)| list.map(|list| (ty, list)))
+            .flat_map(|(ty, list)| {
+                list.resolve(types)
+                    .into_iter()
+                    .map(move |member| format!("`{}` of {}_{}_T", member, ty, name))
+            })
+            .collect();
+    }
+    // Associated constants of the header comment fields found and the `metadata` function
+    pub(crate) fn metadata(&self) -> proc_macro2::TokenStream {
+        let name = self.name.to_string();
+        let fields = [
+            ("MODEL_VERSION", "Model version", &self.version),
+            (
+                "CODER_VERSION",
+                "Simulink Coder version",
+                &self.coder_version,
+            ),
+            (
+                "CODEGEN_DATE",
+                "C/C++ source code generated on",
+                &self.codegen_date,
+            ),
+            ("SOLVER", "Solver", &self.solver),
+        ];
+        let consts = fields.iter().filter_map(|(ident, field, value)| {
+            let ident = Ident::new(ident, Span::call_site());
+            let doc = format!(" The `{}` field of the model header comment", field);
+            value.as_ref().map(|value| {
+                quote! {
+                    #[doc = #doc]
+                    pub const #ident: &'static str = #value;
+                }
+            })
+        });
+        let values = fields.iter().map(|(ident, _, value)| {
+            let ident = Ident::new(ident, Span::call_site());
+            if value.is_some() {
+                quote!(Some(Self::#ident))
+            } else {
+                quote!(None)
+            }
+        });
+        quote! {
+            #(#consts)*
+            /// Returns the model name and the fields of the model header comment
+            pub fn metadata() -> ::simulink_rs::ModelMetadata {
+                let [version, coder_version, codegen_date, solver] = [#(#values),*];
+                ::simulink_rs::ModelMetadata {
+                    name: #name,
+                    version,
+                    coder_version,
+                    codegen_date,
+                    solver,
+                }
+            }
+        }
+    }
+    // JSON description of the model
+    pub(crate) fn json(&self) -> String {
+        format!(
+            "{{\n  \"model\": {},\n  \"version\": {},\n  \"checksum\": {},\n  \"sample_time\": {},\n  \"inputs\": {},\n  \"outputs\": {},\n  \"states\": {},\n  \"continuous_states\": {},\n  \"params\": {}\n}}\n",
+            json_string(&self.name.to_string()),
+            self.version
+                .as_deref()
+                .map_or("null".to_string(), json_string),
+            self.checksum
+                .map_or("null".to_string(), |c| json_string(&checksum_string(c))),
+            self.sample_time
+                .map_or("null".to_string(), |ts| ts.to_string()),
+            self.inputs.as_ref().map_or("null".to_string(), List::json),
+            self.outputs.as_ref().map_or("null".to_string(), List::json),
+            self.states.as_ref().map_or("null".to_string(), List::json),
+            self.continuous.as_ref().map_or("null".to_string(), List::json),
+            self.params.json()
+        )
+    }
+    // Parse the Simulink C header file
+    pub(crate) fn parse(file_name: &Path) -> std::result::Result<Self, String> {
+        let file = File::open(file_name)
+            .map_err(|e| format!("failed to open the Simulink header {:?}: {}", file_name, e))?;
+        let header = fs::read_to_string(file_name).unwrap_or_default();
+        let defines = parse_defines(&header);
+        let in_header = |e: String| format!("{} in the Simulink header {:?}", e, file_name);
+        let reader = BufReader::new(file);
+        let mut lines = reader.lines().map_while(std::result::Result::ok);
+
+        // the model name of the `File: <model>.h` line or else of the first structure tag,
+        // e.g. `tag_RTM_<model>_T` or `ExtU_<model>_T`, for the headers without the comment
+        let file_regex = Regex::new(r"File:\s*(\w+)\.h").unwrap();
+        let tag_regex = Regex::new(r"\b(?:tag_RTM|RT_MODEL|ExtU|ExtY)_(\w+?)_T\b").unwrap();
+        let model = match file_regex
+            .captures(&header)
+            .or_else(|| tag_regex.captures(&header))
+        {
+            Some(captures) => Ident::new(&captures[1], Span::call_site()),
+            None => {
+                return Err(format!(
+                    "the Simulink model name couldn't be inferred: no `File: <model>.h` line \
+                     nor `tag_RTM_<model>_T`, `RT_MODEL_<model>_T`, `ExtU_<model>_T` or \
+                     `ExtY_<model>_T` structure found in the header {:?}, add the line to \
+                     the model header comment or, if it isn't the model header, give the model \
+                     header explicitly, e.g. `import!(Control, \"sys/<model>.h\")`",
+                    file_name
+                ))
+            }
+        };
+        diagnostic!("Parsing Simulink model {}:", model);
+
+        let version_regex = Regex::new(r"Model version\s*:\s*(\S+)").unwrap();
+        let mut version = None;
+        // the ` * <field> : <value>` lines of the header comment
+        let comment_field =
+            |field: &str| Regex::new(&format!(r"^\s*\*\s*{}\s*:\s*(.*?)\s*$", field)).unwrap();
+        let fields_regex = [
+            comment_field("Simulink Coder version"),
+            comment_field(r"C/C\+\+ source code generated on"),
+            comment_field("Solver"),
+        ];
+        let mut fields: [Option<String>; 3] = Default::default();
+        let mut model_inputs = None;
+        let mut model_outputs = None;
+        let mut model_states = None;
+        let mut model_continuous = None;
+        let mut model_zero_crossings = None;
+        let mut model_params = List::default();
+        // the Simulink structures are found by their tag, e.g. `ExtU` for `} ExtU_<model>_T;`,
+        // whatever the comment before them, e.g. `/* Block states ... */`
+        // or `/* Block signals and states ... */`
+        let opening = Regex::new(r"^(?:typedef\s+)?struct\b[^;]*$").unwrap();
+        let tag = Regex::new(&format!(
+            r"^(?:\}}\s*|(?:typedef\s+)?struct\s+)(ExtU|ExtY|DW|X|PrevZCX|P)_{}_T_?\b",
+            model
+        ))
+        .unwrap();
+        while let Some(line) = lines.next() {
+            if version.is_none() {
+                version = version_regex
+                    .captures(&line)
+                    .map(|caps| caps[1].to_string());
+            }
+            for (regex, field) in fields_regex.iter().zip(fields.iter_mut()) {
+                if field.is_none() {
+                    *field = regex
+                        .captures(&line)
+                        .map(|caps| caps[1].to_string())
+                        .filter(|value| !value.is_empty());
+                }
+            }
+            if !opening.is_match(strip_comments(&line).trim()) {
+                continue;
+            }
+            let structure = structure_lines(line, &mut lines, &model.to_string());
+            // the tag of the closing line of the typedef or of the opening line of the structure
+            let Some(io) = [structure.last(), structure.first()]
+                .into_iter()
+                .flatten()
+                .find_map(|line| {
+                    tag.captures(strip_comments(line).trim())
+                        .map(|caps| caps[1].to_string())
+                })
+            else {
+                continue;
+            };
+            let list = parse_io(&mut structure.into_iter(), &io, &defines).map_err(in_header)?;
+            if let Some(list) = list {
+                match io.as_str() {
+                    "ExtU" => model_inputs = Some(list),
+                    "ExtY" => model_outputs = Some(list),
+                    "DW" => model_states = Some(list),
+                    "X" => model_continuous = Some(list),
+                    "PrevZCX" => model_zero_crossings = Some(list),
+                    _ => model_params = list,
+                }
+            }
+        }
+        let [coder_version, codegen_date, solver] = fields;
+        Ok(Self {
+            name: model,
+            version,
+            coder_version,
+            codegen_date,
+            solver,
+            inputs: model_inputs,
+            outputs: model_outputs,
+            states: model_states,
+            continuous: model_continuous,
+            zero_crossings: model_zero_crossings,
+            params: model_params,
+            params_init: None,
+            checksum: None,
+            sample_time: None,
+            precision: Primitive::F64,
+            unrecognized: vec![],
+        })
+    }
+}
@@ -0,0 +1,738 @@
+//! The parsing of the Simulink C headers and C files
+
+use crate::io::{List, IO};
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::{env, fs};
+
+// The names of the members of the C structure `body`
+pub(crate) fn struct_members(body: &str) -> Vec<String> {
+    let comments = Regex::new(r"(?s)/\*.*?\*/|//[^\n]*").unwrap();
+    let name = Regex::new(r"(\w+)\s*(?:\[[^\]]*\]\s*)*$").unwrap();
+    comments
+        .replace_all(body, "")
+        .split(';')
+        .filter_map(|member| name.captures(member.trim()))
+        .map(|caps| caps[1].to_string())
+        .collect()
+}
+
+// Find the body of the C structure declared with `declaration`
+pub(crate) fn struct_body<'a>(header: &'a str, declaration: &str) -> Option<&'a str> {
+    let start = header.find(declaration)? + declaration.len();
+    let open = start + header[start..].find('{')?;
+    if !header[start..open].trim().is_empty() {
+        return None;
+    }
+    let mut depth = 0;
+    for (i, c) in header[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&header[open + 1..open + i]);
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+// Remove the `const` and `volatile` type qualifiers from a C declaration,
+// e.g. `real_T volatile x;` is `real_T x;`
+pub(crate) fn strip_qualifiers(line: &str) -> std::borrow::Cow<'_, str> {
+    static QUALIFIER: OnceLock<Regex> = OnceLock::new();
+    let qualifier = QUALIFIER.get_or_init(|| Regex::new(r"\b(?:const|volatile)\b\s*").unwrap());
+    qualifier.replace_all(line, "")
+}
+
+// Remove the compiler attributes (`__attribute__((...))`, `__declspec(...)`)
+// and qualifiers (`__restrict`) from a C declaration
+fn strip_attributes(line: &str) -> String {
+    static ATTRIBUTE: OnceLock<Regex> = OnceLock::new();
+    let attribute = ATTRIBUTE.get_or_init(|| {
+        Regex::new(r"\b(?:__attribute__|__declspec|__restrict__|__restrict)\b\s*").unwrap()
+    });
+    let mut stripped = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(m) = attribute.find(rest) {
+        stripped.push_str(&rest[..m.start()]);
+        rest = &rest[m.end()..];
+        // skip the attribute arguments with balanced parentheses
+        if rest.starts_with('(') {
+            let mut depth = 0;
+            let end = rest
+                .char_indices()
+                .find(|&(_, c)| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => (),
+                    }
+                    depth == 0
+                })
+                .map_or(rest.len(), |(i, _)| i + 1);
+            rest = rest[end..].trim_start();
+        }
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+// The Simulink C model directory relative to the crate root: the `dir` argument,
+// else the `SIMULINK_BINDER_DIR` environment variable, else `sys`
+pub(crate) fn sys_dir(dir: Option<&syn::LitStr>) -> PathBuf {
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).to_path_buf();
+    let dir = dir
+        .map(syn::LitStr::value)
+        .or_else(|| env::var("SIMULINK_BINDER_DIR").ok())
+        .unwrap_or_else(|| "sys".to_string());
+    root.join(dir)
+}
+
+// The C files and headers in the `sys` directory
+pub(crate) fn sources(sys: &Path) -> Vec<PathBuf> {
+    fs::read_dir(sys)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| matches!(path.extension().and_then(|e| e.to_str()), Some("c" | "h")))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// The C header files in the `sys` directory
+pub(crate) fn headers(sys: &Path) -> Vec<PathBuf> {
+    fs::read_dir(sys)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|file_name| file_name.extension().and_then(|e| e.to_str()) == Some("h"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// The file name suffixes of the generated headers that aren't the model header
+pub(crate) const EXCLUDED_HEADERS: &[&str] = &[
+    "rtwtypes.h",
+    "rtwtypes_sf.h",
+    "rt_defines.h",
+    "rt_nonfinite.h",
+    "rtGetInf.h",
+    "rtGetNaN.h",
+    "rt_logging.h",
+    "multiword_types.h",
+    "builtin_typeid_types.h",
+    "ert_main.h",
+    "_private.h",
+    "_types.h",
+    "_capi.h",
+    "_capi_host.h",
+    "_dt.h",
+];
+
+// The model header in the `sys` directory
+//
+// The headers ending with one of the `excluded` suffixes are left out, then, if more than one
+// header remains, the headers named after a model, i.e. `<model>.h` for the
+// `Code generated for Simulink model '<model>'` comment of a header or for the
+// wrapper name, whatever the case, are preferred
+pub(crate) fn model_header(
+    sys: &Path,
+    control: &syn::Ident,
+    excluded: &[String],
+) -> std::result::Result<PathBuf, String> {
+    let mut candidates = candidate_headers(sys, excluded);
+    if candidates.len() > 1 {
+        let regex = Regex::new(r"Code generated for Simulink model '(\w+)'").unwrap();
+        let mut models = vec![control.to_string()];
+        models.extend(candidates.iter().filter_map(|file_name| {
+            let header = fs::read_to_string(file_name).ok()?;
+            regex
+                .captures(&header)
+                .map(|captures| captures[1].to_string())
+        }));
+        let named: Vec<_> = candidates
+            .iter()
+            .filter(|file_name| {
+                file_name
+                    .file_stem()
+                    .and_then(|f| f.to_str())
+                    .filter(|f| models.iter().any(|model| model.eq_ignore_ascii_case(f)))
+                    .is_some()
+            })
+            .cloned()
+            .collect();
+        if !named.is_empty() {
+            candidates = named;
+        }
+    }
+    match candidates.as_slice() {
+        [] => Err(format!("no Simulink header found in {:?}", sys)),
+        [file_name] => Ok(file_name.clone()),
+        _ => {
+            // the headers relative to the crate root, as the explicit header argument
+            let candidates: Vec<_> = candidates.iter().map(|f| crate_relative(f)).collect();
+            Err(format!(
+                "more than one Simulink header found: {}, give the model header explicitly, \
+                 e.g. `import!({}, \"{}\")`, or leave the other headers out with the \
+                 `exclude = [\"<suffix>\", ...]` argument",
+                candidates.join(", "),
+                control,
+                candidates[0]
+            ))
+        }
+    }
+}
+
+// The `<model>.h` header of the Simulink model `model` in the `sys` directory,
+// the headers ending with one of the `excluded` suffixes left out
+pub(crate) fn named_model_header(
+    sys: &Path,
+    model: &str,
+    excluded: &[String],
+) -> std::result::Result<PathBuf, String> {
+    let candidates = candidate_headers(sys, excluded);
+    let stem = |file_name: &PathBuf| {
+        file_name
+            .file_stem()
+            .and_then(|f| f.to_str())
+            .map(String::from)
+    };
+    match candidates
+        .iter()
+        .find(|file_name| stem(file_name).as_deref() == Some(model))
+    {
+        Some(file_name) => Ok(file_name.clone()),
+        None => {
+            let models: Vec<_> = candidates.iter().filter_map(stem).collect();
+            Err(format!(
+                "no header of the Simulink model `{}` found in `{}`, the available models are: {}",
+                model,
+                crate_relative(sys),
+                if models.is_empty() {
+                    "none".to_string()
+                } else {
+                    models.join(", ")
+                }
+            ))
+        }
+    }
+}
+
+// The `path` relative to the crate root, or else the `path` itself
+fn crate_relative(path: &Path) -> String {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+// The headers of the `sys` directory, sorted by name, that don't end with one of
+// the `excluded` suffixes
+fn candidate_headers(sys: &Path, excluded: &[String]) -> Vec<PathBuf> {
+    let mut candidates: Vec<_> = headers(sys)
+        .into_iter()
+        .filter(|file_name| {
+            file_name
+                .file_name()
+                .and_then(|f| f.to_str())
+                .filter(|f| !excluded.iter().any(|suffix| f.ends_with(suffix.as_str())))
+                .is_some()
+        })
+        .collect();
+    candidates.sort();
+    candidates
+}
+
+// Parse the model checksum from the `rtmSetChecksumVal(.., i, value)` or
+// the `ssSetChecksumVal(.., i, value)` calls in the `sys` C files, or else from the
+// checksums initializer, e.g. `checksums[4] = { 0x1A2B3C4DU, ... };`
+pub(crate) fn parse_checksum(sys: &Path) -> Option<[u32; 4]> {
+    let value = r"(?:0[xX][[:xdigit:]]+|\d+)[uU]?";
+    let regex = Regex::new(&format!(
+        r"(?:rtm|ss)SetChecksumVal\([^,]+,\s*(?P<i>[0-3])\s*,\s*(?P<value>{})\s*\)",
+        value
+    ))
+    .unwrap();
+    let initializer = Regex::new(&format!(
+        r"\w*checksums?\w*\s*(?:\[\s*4?\s*\])?\s*=\s*\{{\s*(?P<values>{v}\s*,\s*{v}\s*,\s*{v}\s*,\s*{v})\s*\}}",
+        v = value
+    ))
+    .unwrap();
+    let parse = |value: &str| {
+        let value = value.trim().trim_end_matches(['u', 'U']);
+        match value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+        {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => value.parse::<u32>().ok(),
+        }
+    };
+    let mut checksum = [None; 4];
+    let mut initialized = None;
+    for entry in fs::read_dir(sys).ok()?.flatten() {
+        let file_name = entry.path();
+        if !matches!(
+            file_name.extension().and_then(|e| e.to_str()),
+            Some("c" | "h")
+        ) {
+            continue;
+        }
+        if let Ok(source) = fs::read_to_string(&file_name) {
+            for caps in regex.captures_iter(&source) {
+                let i: usize = caps["i"].parse().ok()?;
+                checksum[i] = parse(&caps["value"]);
+            }
+            if let Some(caps) = initializer.captures(&source) {
+                let values: Option<Vec<_>> = caps["values"].split(',').map(parse).collect();
+                initialized = initialized.or(values.and_then(|values| values.try_into().ok()));
+            }
+        }
+    }
+    match checksum {
+        [Some(c0), Some(c1), Some(c2), Some(c3)] => Some([c0, c1, c2, c3]),
+        _ => initialized,
+    }
+}
+// Parse the values of the parameters initializer `P_<model>_T <model>_P = { ... };`
+// of the `sys` C files, the row-major scalar elements of the parameters in order
+//
+// The initializer isn't parsed if a value isn't a number literal
+pub(crate) fn parse_params_init(model: &str, sys: &Path) -> Option<Vec<f64>> {
+    let comment = Regex::new(r"(?s)/\*.*?\*/|//[^\n]*").unwrap();
+    let initializer = Regex::new(&format!(r"\bP_{}_T\s+\w+\s*=\s*\{{", model)).unwrap();
+    let source = fs::read_dir(sys)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|file_name| file_name.extension().and_then(|e| e.to_str()) == Some("c"))
+        .filter_map(|file_name| fs::read_to_string(file_name).ok())
+        .map(|source| comment.replace_all(&source, "").into_owned())
+        .find(|source| initializer.is_match(source))?;
+    let start = initializer.find(&source)?.end();
+    // the values up to the closing brace of the initializer, whatever the nested braces
+    let mut depth = 1;
+    let end = source[start..].find(|c| {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => (),
+        }
+        depth == 0
+    })?;
+    source[start..start + end]
+        .split(['{', '}', ','])
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| match value {
+            "true" => Some(1.),
+            "false" => Some(0.),
+            _ => value
+                .trim_end_matches(['u', 'U', 'l', 'L', 'f', 'F'])
+                .parse()
+                .ok(),
+        })
+        .collect()
+}
+// Parse the model fundamental sample time in seconds from the `Fixed step size: <Ts>` comment
+// of the model `header`, or else from the smallest `Sample time: [<Ts>s, <offset>s]` comment
+// of the model header and of the `sys` C files, or else from the `stepSize0 = <Ts>` or
+// `rtsiSetFixedStepSize(.., <Ts>)` statement of the `sys` C files
+pub(crate) fn parse_sample_time(header: &Path, sys: &Path) -> Option<f64> {
+    let number = r"([-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?)";
+    let header = fs::read_to_string(header).unwrap_or_default();
+    let sources: Vec<String> = fs::read_dir(sys)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|file_name| file_name.extension().and_then(|e| e.to_str()) == Some("c"))
+                .filter_map(|file_name| fs::read_to_string(file_name).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let fixed_step = Regex::new(&format!(
+        r"Fixed step size(?: in seconds)?\s*:\s*{}",
+        number
+    ))
+    .unwrap();
+    if let Some(ts) = fixed_step
+        .captures(&header)
+        .and_then(|caps| caps[1].parse().ok())
+    {
+        return Some(ts);
+    }
+    let sample_time = Regex::new(&format!(r"Sample time:\s*\[\s*{}s?\s*,", number)).unwrap();
+    let fundamental = std::iter::once(&header)
+        .chain(&sources)
+        .flat_map(|text| {
+            sample_time
+                .captures_iter(text)
+                .filter_map(|caps| caps[1].parse::<f64>().ok())
+        })
+        .filter(|&ts| ts > 0.0)
+        .reduce(f64::min);
+    if fundamental.is_some() {
+        return fundamental;
+    }
+    let step_size = Regex::new(&format!(
+        r"(?:stepSize0\s*=|rtsiSetFixedStepSize\s*\([^,]+,)\s*{}",
+        number
+    ))
+    .unwrap();
+    sources.iter().find_map(|source| {
+        step_size
+            .captures(source)
+            .and_then(|caps| caps[1].parse().ok())
+    })
+}
+// The checksum as 4 space separated decimal numbers
+pub(crate) fn checksum_string(checksum: [u32; 4]) -> String {
+    checksum.map(|c| c.to_string()).join(" ")
+}
+
+// FNV-1a 32 bits hash
+pub(crate) fn fnv1a(data: &str) -> u32 {
+    data.bytes().fold(0x811c9dc5u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    })
+}
+
+// JSON string literal
+pub(crate) fn json_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// Simulink structure field declaration, e.g. `real_T u[6];`, compiled once for all the macro calls
+fn field_regex() -> &'static Regex {
+    static FIELD: OnceLock<Regex> = OnceLock::new();
+    FIELD.get_or_init(|| {
+        // `ZCSigState` is the zero-crossing signal state of `zero_crossing_types.h`
+        Regex::new(r"(?P<ctype>\w+_T|\bZCSigState)\s+(?P<name>\w+)(?P<size>(?:\s*\[[^\]\[;]+\])+)?")
+            .unwrap()
+    })
+}
+
+// Parse the integer `#define NAME 42` macros of a C header file
+pub(crate) fn parse_defines(header: &str) -> HashMap<String, usize> {
+    let define = Regex::new(r"(?m)^\s*#\s*define\s+(\w+)\s+\(?\s*(\d+)[uU]?\s*\)?\s*$").unwrap();
+    define
+        .captures_iter(header)
+        .filter_map(|caps| Some((caps[1].to_string(), caps[2].parse().ok()?)))
+        .collect()
+}
+
+// Array dimensions with the `#define` macros replaced by their values, e.g. `[NUM_ACT]` is `[42]`
+// and `[2*N+1]` is `[7]` with `#define N 3`
+fn resolve_size(
+    name: &str,
+    size: &str,
+    defines: &HashMap<String, usize>,
+) -> std::result::Result<String, String> {
+    size.split(['[', ']'])
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| match eval_size(dim, defines) {
+            Ok(n) => Ok(format!("[{}]", n)),
+            Err(SizeError::Unresolved) => {
+                Err(format!("unresolved array size `{}` of `{}`", dim, name))
+            }
+            Err(SizeError::Invalid) => Err(format!(
+                "the array size `{}` of `{}` isn't a positive integer expression \
+                 of literals, `#define` macros, `+`, `-`, `*` and parentheses",
+                dim, name
+            )),
+        })
+        .collect()
+}
+
+// The reasons an array size isn't evaluated
+enum SizeError {
+    // an identifier isn't an integer `#define` macro
+    Unresolved,
+    // the expression isn't integer arithmetic or its value isn't positive
+    Invalid,
+}
+
+// Evaluates the array size `size`, integer literals and `#define` macros
+// combined with `+`, `-`, `*` and parentheses, e.g. `2*N+1`
+fn eval_size(
+    size: &str,
+    defines: &HashMap<String, usize>,
+) -> std::result::Result<usize, SizeError> {
+    static TOKEN: OnceLock<Regex> = OnceLock::new();
+    let token = TOKEN.get_or_init(|| Regex::new(r"\s*(?:(\d+)[uUlL]*|(\w+)|([-+*()]))").unwrap());
+    let mut tokens = vec![];
+    let mut rest = size;
+    while !rest.trim().is_empty() {
+        let caps = token
+            .captures(rest)
+            .filter(|caps| caps.get(0).unwrap().start() == 0)
+            .ok_or(SizeError::Invalid)?;
+        tokens.push(if let Some(n) = caps.get(1) {
+            Token::Value(n.as_str().parse().map_err(|_| SizeError::Invalid)?)
+        } else if let Some(ident) = caps.get(2) {
+            let value = *defines.get(ident.as_str()).ok_or(SizeError::Unresolved)?;
+            Token::Value(i64::try_from(value).map_err(|_| SizeError::Invalid)?)
+        } else {
+            Token::Op(caps[3].chars().next().unwrap())
+        });
+        rest = &rest[caps.get(0).unwrap().end()..];
+    }
+    enum Token {
+        Value(i64),
+        Op(char),
+    }
+    // expr := term (('+' | '-') term)*, term := factor ('*' factor)*,
+    // factor := value | '-' factor | '(' expr ')'
+    fn expr(tokens: &[Token], i: &mut usize) -> Option<i64> {
+        let mut value = term(tokens, i)?;
+        while let Some(Token::Op(op @ ('+' | '-'))) = tokens.get(*i) {
+            *i += 1;
+            let rhs = term(tokens, i)?;
+            value = if *op == '+' {
+                value.checked_add(rhs)?
+            } else {
+                value.checked_sub(rhs)?
+            };
+        }
+        Some(value)
+    }
+    fn term(tokens: &[Token], i: &mut usize) -> Option<i64> {
+        let mut value = factor(tokens, i)?;
+        while let Some(Token::Op('*')) = tokens.get(*i) {
+            *i += 1;
+            value = value.checked_mul(factor(tokens, i)?)?;
+        }
+        Some(value)
+    }
+    fn factor(tokens: &[Token], i: &mut usize) -> Option<i64> {
+        let token = tokens.get(*i)?;
+        *i += 1;
+        match token {
+            Token::Value(value) => Some(*value),
+            Token::Op('-') => factor(tokens, i)?.checked_neg(),
+            Token::Op('(') => {
+                let value = expr(tokens, i)?;
+                matches!(tokens.get(*i), Some(Token::Op(')'))).then(|| *i += 1)?;
+                Some(value)
+            }
+            Token::Op(_) => None,
+        }
+    }
+    let mut i = 0;
+    expr(&tokens, &mut i)
+        .filter(|_| i == tokens.len())
+        .and_then(|value| usize::try_from(value).ok())
+        .filter(|&value| value > 0)
+        .ok_or(SizeError::Invalid)
+}
+
+// Parse the Simulink C header file to extract inputs and outputs variables,
+// the array sizes can be integer arithmetic on the `#define` macros of the header
+pub(crate) fn parse_io(
+    lines: &mut impl Iterator<Item = String>,
+    io: &str,
+    defines: &HashMap<String, usize>,
+) -> std::result::Result<Option<List>, String> {
+    let re = field_regex();
+    // skipping the C++ `extern "C"` and `namespace` wrappers
+    let line = loop {
+        match lines.next() {
+            Some(line) if is_wrapper(&line) => continue,
+            Some(line) => break line,
+            _ => return Ok(None),
+        }
+    };
+    // the lines may be indented and end with `\r` if generated on Windows
+    let line = line.trim();
+    // C `typedef struct {`, C++ `struct ExtU_<model>_T {` or parameters `struct P_<model>_T_ {`
+    let opening = Regex::new(&format!(r"^(?:typedef\s+struct\b|struct\s+{}_)", io)).unwrap();
+    // the closing `} ExtU_<model>_T;` of the typedef, whatever the braces count
+    let closing = Regex::new(&format!(r"^\}}\s*{}_\w+\s*;", io)).unwrap();
+    if opening.is_match(line) {
+        diagnostic!("| {}:", io);
+        let mut io_data = vec![];
+        let mut pointers = false;
+        let mut unparsed = vec![];
+        let mut depth = line.matches('{').count() as isize;
+        let mut opened = depth > 0;
+        // the variables of the nested structures `struct {...} <member>;` being parsed,
+        // with their tag and the number of anonymous structures they enclose
+        let mut nested: Vec<(Vec<IO>, Option<String>, usize)> = vec![];
+        let mut anonymous = 0;
+        let nested_open = Regex::new(r"^struct\b\s*(\w*)").unwrap();
+        let nested_close = Regex::new(r"^\}\s*(\w+)\s*(\[)?").unwrap();
+        // the member declaration up to its `;`, if it is wrapped over several lines,
+        // and whether the line is within a comment continued from the previous lines
+        let mut declaration = String::new();
+        let mut in_comment = false;
+        for line in lines.by_ref() {
+            // the braces of the comments, e.g. `/* '<S1>/{u}' */`, are left out
+            let code = strip_comments(&line);
+            let code = code.trim();
+            let was_opened = opened;
+            opened |= code.contains('{');
+            depth += code.matches('{').count() as isize - code.matches('}').count() as isize;
+            if opened && (depth <= 0 || closing.is_match(code)) {
+                break;
+            }
+            let continued = in_comment;
+            let member = strip_continued_comment(&line, &mut in_comment);
+            if continued && member.trim().is_empty() {
+                continue;
+            }
+            if was_opened {
+                if let Some(caps) = nested_open.captures(code) {
+                    declaration.clear();
+                    let tag = Some(caps[1].to_string()).filter(|tag| !tag.is_empty());
+                    nested.push((std::mem::take(&mut io_data), tag, anonymous));
+                    anonymous = 0;
+                    continue;
+                }
+                if let Some(caps) = nested_close.captures(code).filter(|_| !nested.is_empty()) {
+                    declaration.clear();
+                    let (outer, tag, outer_anonymous) = nested.pop().unwrap();
+                    let members = std::mem::replace(&mut io_data, outer);
+                    anonymous = outer_anonymous;
+                    if caps.get(2).is_some() {
+                        // arrays of structures aren't flattened
+                        diagnostic!("|  ! {}", code);
+                        unparsed.push(code.to_string());
+                        continue;
+                    }
+                    let ty = tag.unwrap_or_else(|| {
+                        anonymous += 1;
+                        format!("__bindgen_ty_{}", anonymous)
+                    });
+                    for mut io in members {
+                        io.name = format!("{}.{}", &caps[1], io.name);
+                        io.structs.insert(0, ty.clone());
+                        io_data.push(io);
+                    }
+                    continue;
+                }
+            }
+            let member = member.trim();
+            if member.is_empty() || member == "{" || member.starts_with('#') {
+                continue;
+            }
+            declaration.push_str(member);
+            if !member.ends_with(';') {
+                declaration.push(' ');
+                continue;
+            }
+            let line = std::mem::take(&mut declaration);
+            pointers |= is_pointer(&line);
+            if let Some(caps) = re.captures(&strip_qualifiers(&strip_attributes(&line))) {
+                let size = caps
+                    .name("size")
+                    .map(|m| resolve_size(&caps["name"], m.as_str(), defines))
+                    .transpose()?;
+                let io = IO::new(&caps["name"], size.as_deref(), &caps["ctype"]);
+                diagnostic!("|  - {:<22}: {:>5}", io.name, io.dims());
+                io_data.push(io)
+            } else if let Some(member) = unparsed_member(&line) {
+                diagnostic!("|  ! {}", member);
+                unparsed.push(member);
+            }
+        }
+        Ok(Some(List(io_data, pointers, unparsed)))
+    } else {
+        Ok(None)
+    }
+}
+
+// The lines of the structure opened on the line `first` up to its closing line,
+// the closing `} <tag>_<model>_T;` of the typedef or the closing brace of the structure
+pub(crate) fn structure_lines(
+    first: String,
+    lines: &mut impl Iterator<Item = String>,
+    model: &str,
+) -> Vec<String> {
+    let closing = Regex::new(&format!(r"^\}}\s*\w+_{}_T\s*;", model)).unwrap();
+    let mut structure = vec![];
+    let (mut depth, mut opened) = (0isize, false);
+    let mut line = Some(first);
+    while let Some(next) = line {
+        let code = strip_comments(&next);
+        let code = code.trim();
+        opened |= code.contains('{');
+        depth += code.matches('{').count() as isize - code.matches('}').count() as isize;
+        let closed = opened && (depth <= 0 || closing.is_match(code));
+        structure.push(next);
+        if closed {
+            break;
+        }
+        line = lines.next();
+    }
+    structure
+}
+
+// Pointer member declaration, e.g. `void *LoggedData;`, `real_T *x[2];` or `void (*f)(void);`,
+// the comments, also those continued on the next lines, are left out
+fn is_pointer(line: &str) -> bool {
+    static POINTER: OnceLock<Regex> = OnceLock::new();
+    let pointer = POINTER
+        .get_or_init(|| Regex::new(r"\*\s*\w+\s*(?:\[[^\]]*\]\s*)*;|\(\s*\*\s*\w+\s*\)").unwrap());
+    pointer.is_match(&strip_comments(line))
+}
+
+// The line without its comments, also those continued on the next lines
+pub(crate) fn strip_comments(line: &str) -> std::borrow::Cow<'_, str> {
+    comment_regex().replace_all(line, "")
+}
+
+// C comment, up to the end of the line if it isn't closed on the line
+fn comment_regex() -> &'static Regex {
+    static COMMENT: OnceLock<Regex> = OnceLock::new();
+    COMMENT.get_or_init(|| Regex::new(r"/\*.*?(?:\*/|$)|//.*").unwrap())
+}
+
+// The line without its comments, the start of the line if `in_comment` continues the comment of
+// the previous lines, `in_comment` is then set if the comment of the line isn't closed
+fn strip_continued_comment(line: &str, in_comment: &mut bool) -> String {
+    let line = if *in_comment {
+        match line.find("*/") {
+            Some(end) => &line[end + 2..],
+            None => return String::new(),
+        }
+    } else {
+        line
+    };
+    *in_comment = comment_regex()
+        .find_iter(line)
+        .last()
+        .is_some_and(|m| m.as_str().starts_with("/*") && !m.as_str().ends_with("*/"));
+    strip_comments(line).into_owned()
+}
+
+// Member declaration that isn't a pointer nor a nested structure, e.g. `double gain;`,
+// the comments are left out
+fn unparsed_member(line: &str) -> Option<String> {
+    let member = strip_comments(line);
+    let member = member.trim();
+    (member.ends_with(';') && !member.starts_with('}') && !is_pointer(member))
+        .then(|| member.to_string())
+}
+
+// Blank, preprocessor, C++ `extern "C" {` and `namespace X {` lines
+fn is_wrapper(line: &str) -> bool {
+    let line = line.trim();
+    line.is_empty()
+        || line.starts_with('#')
+        || line.starts_with("extern \"C\"")
+        || line.starts_with("namespace ")
+}
@@ -0,0 +1,497 @@
+//! The Simulink real-time model data structure and the model entry points
+
+use crate::io::Receiver;
+use crate::parse::{struct_body, struct_members};
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use regex::Regex;
+
+// Simulink real-time model data structure
+pub(crate) enum Rtm {
+    // built before each call to the model with the pointer to the states and, for reusable
+    // functions taking only the real-time model, the pointers to the inputs and outputs
+    Transient {
+        // the structure states pointer member, usually `dwork`
+        dwork: Option<Ident>,
+        // the structure inputs pointer member
+        inputs: Option<Ident>,
+        // the structure outputs pointer member
+        outputs: Option<Ident>,
+        // the structure parameters pointer member
+        params: Option<Ident>,
+        // the structure continuous states pointer member
+        continuous: Option<Ident>,
+        // the structure previous zero-crossings pointer member
+        zero_crossings: Option<Ident>,
+        // the structure has only the pointer members above, it's then built as a literal
+        literal: bool,
+    },
+    // owned by the wrapper for models with non-inlined S-functions:
+    // the `SimStruct`s set up by the model initialization live inside the structure
+    SimStruct {
+        // the structure states pointer member, usually `dwork`
+        dwork: Option<Ident>,
+        // the structure parameters pointer member
+        params: Option<Ident>,
+        // the structure continuous states pointer member
+        continuous: Option<Ident>,
+        // the structure previous zero-crossings pointer member
+        zero_crossings: Option<Ident>,
+    },
+    // allocated by the model constructor `<model>(void)` and freed by `<model>_terminate`,
+    // the states live in the allocated memory
+    Allocated {
+        // the structure inputs pointer member
+        inputs: Option<Ident>,
+        // the structure outputs pointer member
+        outputs: Option<Ident>,
+        // the structure states pointer member
+        states: Option<Ident>,
+        // the structure parameters pointer member
+        params: Option<Ident>,
+        // the structure continuous states pointer member
+        continuous: Option<Ident>,
+        // the model has a terminate function
+        terminate: bool,
+    },
+}
+impl Rtm {
+    // Parse the `tag_RTM_<model>_T` structure in the Simulink C header file
+    pub(crate) fn parse(header: &str, model: &str) -> Self {
+        let body = struct_body(header, &format!("struct tag_RTM_{}_T", model));
+        let constructor = Regex::new(&format!(
+            r"extern\s+RT_MODEL_{0}_T\s*\*\s*{0}\s*\(\s*(?:void)?\s*\)\s*;",
+            model
+        ))
+        .unwrap();
+        let member = |ty: &str| {
+            body.and_then(|body| {
+                Regex::new(&format!(r"\b{}_{}_T\s*\*\s*(\w+)\s*;", ty, model))
+                    .unwrap()
+                    .captures(body)
+                    .map(|caps| Ident::new(&caps[1], Span::call_site()))
+            })
+        };
+        let params = member("P");
+        let continuous = member("X");
+        let zero_crossings = member("PrevZCX");
+        // the states member is found by its type, or by the `dwork` name if it's untyped
+        let dwork = member("DW").or_else(|| {
+            body.is_none_or(|body| Regex::new(r"\*\s*dwork\s*;").unwrap().is_match(body))
+                .then(|| Ident::new("dwork", Span::call_site()))
+        });
+        if constructor.is_match(header) {
+            diagnostic!("| RTM: allocated");
+            return Self::Allocated {
+                inputs: member("ExtU"),
+                outputs: member("ExtY"),
+                states: member("DW"),
+                params,
+                continuous,
+                terminate: EntryPoint::parse(header, &format!("{}_terminate", model))
+                    .0
+                    .is_some(),
+            };
+        }
+        match body {
+            Some(body) if body.contains("SimStruct") => {
+                diagnostic!("| RTM: SimStruct");
+                Self::SimStruct {
+                    dwork,
+                    params,
+                    continuous,
+                    zero_crossings,
+                }
+            }
+            _ => {
+                let (inputs, outputs) = (member("ExtU"), member("ExtY"));
+                if inputs.is_some() || outputs.is_some() {
+                    diagnostic!("| RTM: reusable");
+                }
+                let mapped: Vec<String> = [
+                    &dwork,
+                    &inputs,
+                    &outputs,
+                    &params,
+                    &continuous,
+                    &zero_crossings,
+                ]
+                .into_iter()
+                .flatten()
+                .map(|member| member.to_string())
+                .collect();
+                // the members of the structure, e.g. `B_<model>_T *blockIO` pointing to
+                // the block signals, that aren't set by the wrapper are left null
+                let members = body.map(struct_members).unwrap_or_else(|| mapped.clone());
+                let unmapped: Vec<_> = members
+                    .iter()
+                    .filter(|member| !mapped.contains(member))
+                    .collect();
+                if !unmapped.is_empty() {
+                    diagnostic!("| RTM: null members {:?}", unmapped);
+                }
+                Self::Transient {
+                    literal: dwork.is_some() && continuous.is_none() && unmapped.is_empty(),
+                    dwork,
+                    inputs,
+                    outputs,
+                    params,
+                    continuous,
+                    zero_crossings,
+                }
+            }
+        }
+    }
+    // The states structure of the wrapper `receiver`
+    pub(crate) fn states(&self, receiver: &Receiver) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::Transient { .. } | Self::SimStruct { .. } => Some(receiver.states()),
+            Self::Allocated { states, .. } => states
+                .as_ref()
+                .map(|states| quote!((*(*#receiver.rtm).#states))),
+        }
+    }
+    // Wrapper is `Copy` if it doesn't own the real-time model
+    pub(crate) fn is_copy(&self) -> bool {
+        matches!(self, Self::Transient { .. })
+    }
+    // Wrapper states and real-time model fields and the `Default` and `Drop` implementations
+    // of the `wrapper` type, `states` is the states field type, if the model has states,
+    // public if `expose`, and `extra` are the other wrapper fields default values
+    pub(crate) fn storage(
+        &self,
+        model: &Ident,
+        wrapper: &Ident,
+        states: Option<&proc_macro2::TokenStream>,
+        expose: bool,
+        extra: &proc_macro2::TokenStream,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let vis = expose.then(|| quote!(pub));
+        let states_field = states.map(|states| quote!(#vis states: #states,));
+        let boxed = if !cfg!(feature = "std") {
+            quote!(alloc::boxed::Box)
+        } else {
+            quote!(Box)
+        };
+        match self {
+            Self::Transient { .. } => (quote!(#states_field), quote!()),
+            Self::SimStruct { .. } => (
+                quote! {
+                    #states_field
+                    // Real-time model structure, boxed as the S-functions keep pointers into it
+                    rtm: #boxed<[<RT_MODEL_ #model _T>]>,
+                },
+                {
+                    let states_default = states.map(|_| quote!(states: Default::default(),));
+                    quote! {
+                        impl Default for #wrapper {
+                            fn default() -> Self {
+                                Self {
+                                    #states_default
+                                    rtm: #boxed::new(unsafe { core::mem::zeroed() }),
+                                    #extra
+                                }
+                            }
+                        }
+                    }
+                },
+            ),
+            Self::Allocated { terminate, .. } => (
+                quote! {
+                    // Real-time model structure allocated by the model
+                    rtm: *mut [<RT_MODEL_ #model _T>],
+                },
+                {
+                    let drop = if *terminate {
+                        quote! {
+                            impl Drop for #wrapper {
+                                fn drop(&mut self) {
+                                    unsafe { [<#model _terminate>](self.rtm) }
+                                }
+                            }
+                        }
+                    } else {
+                        quote!()
+                    };
+                    quote! {
+                        impl Default for #wrapper {
+                            fn default() -> Self {
+                                let rtm = unsafe { #model() };
+                                assert!(!rtm.is_null(), "Simulink model allocation failed");
+                                Self {
+                                    rtm,
+                                    #extra
+                                }
+                            }
+                        }
+                        #drop
+                    }
+                },
+            ),
+        }
+    }
+    // Real-time model setup, pointer and teardown for a call to the model
+    pub(crate) fn quote(
+        &self,
+        model: &Ident,
+        receiver: &Receiver,
+    ) -> (
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+    ) {
+        let (states, inputs_src, outputs_dst, parameters, states_continuous) = (
+            receiver.states(),
+            receiver.inputs(),
+            receiver.outputs(),
+            receiver.parameters(),
+            receiver.continuous(),
+        );
+        let previous_zero_crossings = receiver.zero_crossings();
+        match self {
+            Self::Transient {
+                dwork,
+                inputs,
+                outputs,
+                params,
+                continuous,
+                zero_crossings,
+                literal,
+            } => (
+                match dwork {
+                    Some(dwork) if *literal => {
+                        let inputs = inputs
+                            .as_ref()
+                            .map(|inputs| quote!(#inputs: &mut #inputs_src as *mut _,));
+                        let outputs = outputs
+                            .as_ref()
+                            .map(|outputs| quote!(#outputs: &mut #outputs_dst as *mut _,));
+                        let params = params
+                            .as_ref()
+                            .map(|params| quote!(#params: &mut #parameters as *mut _,));
+                        let zero_crossings = zero_crossings.as_ref().map(|zero_crossings| {
+                            quote!(#zero_crossings: &mut #previous_zero_crossings as *mut _,)
+                        });
+                        quote! {
+                            let mut data: [<RT_MODEL_ #model _T>] = [<tag_RTM_ #model _T>] {
+                                #inputs
+                                #outputs
+                                #dwork: &mut #states as *mut _,
+                                #params
+                                #zero_crossings
+                            };
+                        }
+                    }
+                    _ => {
+                        // the real-time model of a model without states has no states member,
+                        // the one of a model with continuous states has the solver members
+                        // and other members may not be set by the wrapper
+                        let dwork = dwork
+                            .as_ref()
+                            .map(|dwork| quote!(data.#dwork = &mut #states as *mut _;));
+                        let inputs = inputs
+                            .as_ref()
+                            .map(|inputs| quote!(data.#inputs = &mut #inputs_src as *mut _;));
+                        let outputs = outputs
+                            .as_ref()
+                            .map(|outputs| quote!(data.#outputs = &mut #outputs_dst as *mut _;));
+                        let params = params
+                            .as_ref()
+                            .map(|params| quote!(data.#params = &mut #parameters as *mut _;));
+                        let continuous = continuous.as_ref().map(|continuous| {
+                            quote!(data.#continuous = &mut #states_continuous as *mut _;)
+                        });
+                        let zero_crossings = zero_crossings.as_ref().map(|zero_crossings| {
+                            quote!(data.#zero_crossings = &mut #previous_zero_crossings as *mut _;)
+                        });
+                        quote! {
+                            let mut data: [<RT_MODEL_ #model _T>] = unsafe { core::mem::zeroed() };
+                            #inputs
+                            #outputs
+                            #dwork
+                            #params
+                            #continuous
+                            #zero_crossings
+                        }
+                    }
+                },
+                quote!(&mut data as *mut _),
+                quote!(),
+            ),
+            Self::SimStruct {
+                dwork,
+                params,
+                continuous,
+                zero_crossings,
+            } => (
+                {
+                    let dwork = dwork
+                        .as_ref()
+                        .map(|dwork| quote!(#receiver.rtm.#dwork = &mut #states as *mut _;));
+                    let params = params
+                        .as_ref()
+                        .map(|params| quote!(#receiver.rtm.#params = &mut #parameters as *mut _;));
+                    let continuous = continuous.as_ref().map(|continuous| {
+                        quote!(#receiver.rtm.#continuous = &mut #states_continuous as *mut _;)
+                    });
+                    let zero_crossings = zero_crossings.as_ref().map(|zero_crossings| {
+                        quote!(#receiver.rtm.#zero_crossings = &mut #previous_zero_crossings as *mut _;)
+                    });
+                    quote!(#dwork #params #continuous #zero_crossings)
+                },
+                quote!(&mut *#receiver.rtm as *mut _),
+                quote!(),
+            ),
+            // the model may free the parameters it allocated, they are copied instead
+            Self::Allocated {
+                inputs,
+                outputs,
+                params,
+                continuous,
+                ..
+            } => (
+                {
+                    let inputs = inputs
+                        .as_ref()
+                        .map(|inputs| quote!(unsafe { *(*#receiver.rtm).#inputs = #inputs_src };));
+                    let params = params
+                        .as_ref()
+                        .map(|params| quote!(unsafe { *(*#receiver.rtm).#params = #parameters };));
+                    let continuous = continuous.as_ref().map(|continuous| {
+                        quote!(unsafe { *(*#receiver.rtm).#continuous = #states_continuous };)
+                    });
+                    quote!(#inputs #params #continuous)
+                },
+                quote!(#receiver.rtm),
+                {
+                    let outputs = outputs.as_ref().map(
+                        |outputs| quote!(#outputs_dst = unsafe { *(*#receiver.rtm).#outputs };),
+                    );
+                    let continuous = continuous.as_ref().map(|continuous| {
+                        quote!(#states_continuous = unsafe { *(*#receiver.rtm).#continuous };)
+                    });
+                    quote!(#outputs #continuous)
+                },
+            ),
+        }
+    }
+}
+
+// The tasks of a multi-rate model with a step function per task, `<model>_step<task>`,
+// empty for a single-rate model
+pub(crate) fn parse_tasks(header: &str, model: &str) -> Vec<usize> {
+    let prototype = Regex::new(&format!(r"extern\s+void\s+{}_step(\d+)\s*\(", model)).unwrap();
+    let mut tasks: Vec<usize> = prototype
+        .captures_iter(header)
+        .filter_map(|caps| caps[1].parse().ok())
+        .collect();
+    tasks.sort_unstable();
+    tasks.dedup();
+    tasks
+}
+
+// The (period,offset) sample times in seconds of the `tasks` of a multi-rate model, from the
+// `Sample time: [<Ts>s, <offset>s]` comments of the `<model>_step<task>` prototypes, `None`
+// if a task has no sample time
+pub(crate) fn parse_task_periods(
+    header: &str,
+    model: &str,
+    tasks: &[usize],
+) -> Option<Vec<(f64, f64)>> {
+    let number = r"([-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?)";
+    let prototype = Regex::new(&format!(
+        r"extern\s+void\s+{}_step(\d+)\s*\([^)]*\)\s*;\s*/\*\s*Sample time:\s*\[\s*{}s?\s*,\s*{}s?\s*\]",
+        model, number, number
+    ))
+    .unwrap();
+    let periods: Vec<(usize, f64, f64)> = prototype
+        .captures_iter(header)
+        .filter_map(|caps| {
+            Some((
+                caps[1].parse().ok()?,
+                caps[2].parse().ok()?,
+                caps[3].parse().ok()?,
+            ))
+        })
+        .collect();
+    tasks
+        .iter()
+        .map(|task| {
+            periods
+                .iter()
+                .find(|(id, ..)| id == task)
+                .map(|&(_, period, offset)| (period, offset))
+        })
+        .collect()
+}
+
+// Simulink model entry point function
+pub(crate) struct EntryPoint(pub(crate) Option<Vec<String>>);
+impl EntryPoint {
+    // The function takes an argument of the C type `ty` (e.g. `ExtU_`),
+    // the default arguments if the prototype is missing
+    pub(crate) fn takes(&self, ty: &str) -> bool {
+        match &self.0 {
+            Some(args) => args.iter().any(|arg| arg.contains(ty)),
+            None => ["RT_MODEL_", "ExtU_", "ExtY_"].contains(&ty),
+        }
+    }
+    // Parse the function prototype arguments in the Simulink C header file
+    pub(crate) fn parse(header: &str, function: &str) -> Self {
+        let prototype = Regex::new(&format!(
+            r"extern\s+void\s+{}\s*\((?P<args>[^)]*)\)\s*;",
+            function
+        ))
+        .unwrap();
+        Self(prototype.captures(header).map(|caps| {
+            caps["args"]
+                .split(',')
+                .map(|arg| arg.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|arg| !(arg.is_empty() || arg == "void"))
+                .collect()
+        }))
+    }
+    // Function call arguments, defaults to `(RTM, ExtU, ExtY)` if the prototype is missing
+    pub(crate) fn quote(
+        &self,
+        rtm: &proc_macro2::TokenStream,
+        receiver: &Receiver,
+    ) -> proc_macro2::TokenStream {
+        let (inputs, outputs, states, parameters, continuous) = (
+            receiver.inputs(),
+            receiver.outputs(),
+            receiver.states(),
+            receiver.parameters(),
+            receiver.continuous(),
+        );
+        let inputs = quote!(&mut #inputs as *mut _);
+        let outputs = quote!(&mut #outputs as *mut _);
+        match &self.0 {
+            Some(args) => {
+                let args = args.iter().map(|arg| {
+                    if arg.contains("RT_MODEL_") {
+                        rtm.clone()
+                    } else if arg.contains("ExtU_") {
+                        inputs.clone()
+                    } else if arg.contains("ExtY_") {
+                        outputs.clone()
+                    } else if arg.contains("DW_") {
+                        quote!(&mut #states as *mut _)
+                    } else if arg.split_whitespace().any(|word| word.starts_with("P_")) {
+                        quote!(&mut #parameters as *mut _)
+                    } else if arg.split_whitespace().any(|word| word.starts_with("X_")) {
+                        quote!(&mut #continuous as *mut _)
+                    } else if arg.contains("PrevZCX_") {
+                        let zero_crossings = receiver.zero_crossings();
+                        quote!(&mut #zero_crossings as *mut _)
+                    } else {
+                        let msg = format!("unsupported Simulink entry point argument: {}", arg);
+                        quote!(compile_error!(#msg))
+                    }
+                });
+                quote!(#(#args),*)
+            }
+            None => quote!(#rtm, #inputs, #outputs),
+        }
+    }
+}
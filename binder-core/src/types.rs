@@ -0,0 +1,267 @@
+//! The Simulink C types and their Rust primitive types
+
+use crate::enums::parse_enums;
+use crate::parse::strip_qualifiers;
+use quote::quote;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+// Rust primitive type of a Simulink C type
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) enum Primitive {
+    #[default]
+    F64,
+    F32,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    CLong,
+    CULong,
+    CChar,
+    Bool,
+}
+impl Primitive {
+    // Primitive type of a C base type
+    fn from_c(c_type: &str) -> Option<Self> {
+        let c_type = c_type.split_whitespace().collect::<Vec<_>>().join(" ");
+        Some(match c_type.as_str() {
+            "double" => Self::F64,
+            "float" => Self::F32,
+            "signed char" | "int8_t" => Self::I8,
+            "unsigned char" | "uint8_t" => Self::U8,
+            "short" | "short int" | "signed short" | "signed short int" | "int16_t" => Self::I16,
+            "unsigned short" | "unsigned short int" | "uint16_t" => Self::U16,
+            "int" | "signed" | "signed int" | "int32_t" => Self::I32,
+            "unsigned" | "unsigned int" | "uint32_t" => Self::U32,
+            "long long" | "long long int" | "signed long long" | "int64_t" => Self::I64,
+            "unsigned long long" | "unsigned long long int" | "uint64_t" => Self::U64,
+            "long" | "long int" | "signed long" | "signed long int" => Self::CLong,
+            "unsigned long" | "unsigned long int" => Self::CULong,
+            "char" => Self::CChar,
+            "bool" | "_Bool" => Self::Bool,
+            _ => return None,
+        })
+    }
+    // Primitive type of the standard Simulink C types aliases of `rtwtypes.h`
+    fn from_rtwtypes(alias: &str) -> Option<Self> {
+        Some(match alias {
+            "real_T" | "real64_T" | "time_T" => Self::F64,
+            "real32_T" => Self::F32,
+            "int8_T" => Self::I8,
+            "uint8_T" | "uchar_T" | "boolean_T" => Self::U8,
+            "int16_T" => Self::I16,
+            "uint16_T" => Self::U16,
+            "int32_T" | "int_T" => Self::I32,
+            "uint32_T" | "uint_T" => Self::U32,
+            "int64_T" => Self::I64,
+            "uint64_T" => Self::U64,
+            "ulong_T" => Self::CULong,
+            "char_T" | "byte_T" => Self::CChar,
+            "ZCSigState" => Self::U8,
+            _ => return None,
+        })
+    }
+    // Primitive type of its `Debug` name, e.g. `F64`
+    pub(crate) fn from_debug(name: &str) -> Option<Self> {
+        Some(match name {
+            "F64" => Self::F64,
+            "F32" => Self::F32,
+            "I8" => Self::I8,
+            "U8" => Self::U8,
+            "I16" => Self::I16,
+            "U16" => Self::U16,
+            "I32" => Self::I32,
+            "U32" => Self::U32,
+            "I64" => Self::I64,
+            "U64" => Self::U64,
+            "CLong" => Self::CLong,
+            "CULong" => Self::CULong,
+            "CChar" => Self::CChar,
+            "Bool" => Self::Bool,
+            _ => return None,
+        })
+    }
+    // Rust type
+    pub(crate) fn ty(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::F64 => quote!(f64),
+            Self::F32 => quote!(f32),
+            Self::I8 => quote!(i8),
+            Self::U8 => quote!(u8),
+            Self::I16 => quote!(i16),
+            Self::U16 => quote!(u16),
+            Self::I32 => quote!(i32),
+            Self::U32 => quote!(u32),
+            Self::I64 => quote!(i64),
+            Self::U64 => quote!(u64),
+            Self::CLong => quote!(::core::ffi::c_long),
+            Self::CULong => quote!(::core::ffi::c_ulong),
+            Self::CChar => quote!(::core::ffi::c_char),
+            Self::Bool => quote!(bool),
+        }
+    }
+    // Zero literal
+    pub(crate) fn zero(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::F64 => quote!(0f64),
+            Self::F32 => quote!(0f32),
+            Self::I8 => quote!(0i8),
+            Self::U8 => quote!(0u8),
+            Self::I16 => quote!(0i16),
+            Self::U16 => quote!(0u16),
+            Self::I32 => quote!(0i32),
+            Self::U32 => quote!(0u32),
+            Self::I64 => quote!(0i64),
+            Self::U64 => quote!(0u64),
+            Self::Bool => quote!(false),
+            Self::CLong | Self::CULong | Self::CChar => {
+                let ty = self.ty();
+                quote!(0 as #ty)
+            }
+        }
+    }
+    // Literal of `value`
+    pub(crate) fn literal(&self, value: f64) -> proc_macro2::TokenStream {
+        match self {
+            Self::F64 => {
+                let value = proc_macro2::Literal::f64_suffixed(value);
+                quote!(#value)
+            }
+            Self::Bool => {
+                let value = value != 0.;
+                quote!(#value)
+            }
+            _ => {
+                let value = proc_macro2::Literal::f64_suffixed(value);
+                let ty = self.ty();
+                quote!(#value as #ty)
+            }
+        }
+    }
+    // Floating point type
+    pub(crate) fn is_float(&self) -> bool {
+        matches!(self, Self::F64 | Self::F32)
+    }
+    // Little-endian bytes of `value`
+    pub(crate) fn to_le_bytes(self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            Self::Bool => quote!([u8::from(#value)]),
+            _ => quote!(#value.to_le_bytes()),
+        }
+    }
+    // Value from the little-endian `bytes` slice
+    pub(crate) fn read_le_bytes(self, bytes: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            Self::Bool => quote!(#bytes[0] != 0),
+            _ => {
+                let ty = self.ty();
+                quote!(#ty::from_le_bytes(#bytes.try_into().unwrap()))
+            }
+        }
+    }
+    // Conversion of `value` to f64
+    pub(crate) fn to_f64(self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        self.to_float(Self::F64, value)
+    }
+    // Conversion of `value` to the floating point type `float`
+    pub(crate) fn to_float(
+        self,
+        float: Self,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let float_ty = float.ty();
+        match self {
+            _ if self == float => value,
+            Self::Bool => quote!(u8::from(#value) as #float_ty),
+            _ => quote!(#value as #float_ty),
+        }
+    }
+    // Conversion of the `value` of the floating point type `float`
+    pub(crate) fn of_float(
+        self,
+        float: Self,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            _ if self == float => value,
+            Self::Bool => quote!(#value != 0.),
+            _ => {
+                let ty = self.ty();
+                quote!(#value as #ty)
+            }
+        }
+    }
+}
+
+// Simulink C types aliases (e.g. `typedef double real_T;`) and enumerations
+#[derive(Debug, Default)]
+pub(crate) struct TypeMap(pub(crate) HashMap<String, String>);
+impl TypeMap {
+    // Parse the `typedef <C type> <alias>;` declarations of a Simulink C header file,
+    // the `const` and `volatile` qualifiers of the C type are left out
+    pub(crate) fn parse(&mut self, file_name: &Path) {
+        let typedef =
+            Regex::new(r"(?m)^\s*typedef\s+(?P<ctype>[\w ]+?)\s+(?P<alias>\w+)\s*;").unwrap();
+        if let Ok(header) = fs::read_to_string(file_name) {
+            for caps in typedef.captures_iter(&header) {
+                let ctype = strip_qualifiers(&caps["ctype"]);
+                let ctype = ctype.trim();
+                if !(ctype.starts_with("struct")
+                    || ctype.starts_with("union")
+                    || ctype.starts_with("enum"))
+                {
+                    self.0
+                        .entry(caps["alias"].to_string())
+                        .or_insert_with(|| ctype.to_string());
+                }
+            }
+        }
+        // the `#define real_T float` aliases of the C base types
+        let define =
+            Regex::new(r"(?m)^\s*#define\s+(?P<alias>\w+)\s+(?P<ctype>[a-z][a-z ]*?)\s*$").unwrap();
+        if let Ok(header) = fs::read_to_string(file_name) {
+            for caps in define.captures_iter(&header) {
+                if Primitive::from_c(&caps["ctype"]).is_some() {
+                    self.0
+                        .entry(caps["alias"].to_string())
+                        .or_insert_with(|| caps["ctype"].to_string());
+                }
+            }
+        }
+        // the enumerations are bindgen `c_uint` aliases, `c_int` if a value is negative
+        for enumeration in parse_enums(file_name) {
+            let ctype = if enumeration.members.iter().any(|(_, value)| *value < 0) {
+                "int"
+            } else {
+                "unsigned int"
+            };
+            self.0
+                .entry(enumeration.name)
+                .or_insert_with(|| ctype.to_string());
+        }
+    }
+    // Rust primitive type of a C type or alias
+    //
+    // Aliases not declared in the headers fall back to the standard `rtwtypes.h` aliases
+    pub(crate) fn primitive(&self, ctype: &str) -> Option<Primitive> {
+        let mut ctype = ctype;
+        // follows the chain of aliases, e.g. `byte_T -> char_T -> char`
+        for _ in 0..8 {
+            if let Some(primitive) = Primitive::from_c(ctype) {
+                return Some(primitive);
+            }
+            match self.0.get(ctype) {
+                Some(alias) => ctype = alias,
+                None => return Primitive::from_rtwtypes(ctype),
+            }
+        }
+        None
+    }
+}
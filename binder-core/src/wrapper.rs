@@ -0,0 +1,1416 @@
+//! The wrapper of the Simulink model and the generators of its core items
+
+use crate::args::Args;
+use crate::import::parse_ic;
+use crate::io::{List, Receiver, IO};
+use crate::model::Model;
+use crate::parse::{checksum_string, fnv1a};
+use crate::rtm::{parse_task_periods, parse_tasks, EntryPoint, Rtm};
+use crate::types::Primitive;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use regex::Regex;
+use syn::Result;
+
+// Real-time model setup before a call to the model, pointer argument of the call and
+// teardown after the call, with the copies from and to the global I/O of the model
+pub(crate) struct ModelCall {
+    pub(crate) setup: TokenStream,
+    pub(crate) ptr: TokenStream,
+    pub(crate) post: TokenStream,
+}
+
+// Simulink model wrapper, the parsed model and the `import!` options shared by the
+// generators of the wrapper items
+pub(crate) struct Wrapper {
+    // the name of the Simulink model
+    pub(crate) model: Ident,
+    // the macro first argument, prefixing the names of the wrapper companion types
+    pub(crate) control: Ident,
+    // the wrapper type, named after the model unless renamed
+    pub(crate) wrapper: Ident,
+    // the wrapper visibility
+    pub(crate) vis: syn::Visibility,
+    // the `import!` options
+    pub(crate) catch_unwind: bool,
+    pub(crate) output_range: bool,
+    pub(crate) deadband: bool,
+    pub(crate) interior_mutability: bool,
+    pub(crate) init: Option<syn::LitStr>,
+    pub(crate) cache_aligned: bool,
+    pub(crate) expose_states: bool,
+    pub(crate) skip_states: Vec<Ident>,
+    // the Simulink structures, empty if missing in the header
+    pub(crate) inputs: List,
+    pub(crate) outputs: List,
+    pub(crate) states: List,
+    pub(crate) continuous: List,
+    pub(crate) zero_crossings: List,
+    pub(crate) params: List,
+    // the values of the parameters initializer of the model data C file
+    pub(crate) params_init: Option<Vec<f64>>,
+    // the Simulink structures present in the header
+    pub(crate) has_inputs: bool,
+    pub(crate) has_outputs: bool,
+    pub(crate) has_states: bool,
+    pub(crate) has_continuous: bool,
+    pub(crate) has_zero_crossings: bool,
+    // the model checksum, fundamental sample time and `real_T` type
+    pub(crate) checksum: Option<[u32; 4]>,
+    pub(crate) sample_time: Option<f64>,
+    pub(crate) precision: Primitive,
+    // the model header contents
+    pub(crate) header: String,
+    // the real-time model
+    pub(crate) rtm: Rtm,
+    // the model entry points
+    pub(crate) initialize_fn: EntryPoint,
+    pub(crate) step_fn: EntryPoint,
+    pub(crate) terminate_fn: EntryPoint,
+    // the tasks of a multi-rate model, empty for a single-rate model
+    pub(crate) tasks: Vec<usize>,
+    pub(crate) single_rate: bool,
+    // the methods and conversions using `std` or `alloc` are left out without the `std` feature
+    pub(crate) no_std: bool,
+    // the step trace hook, for the models with inputs and outputs
+    pub(crate) trace: bool,
+    // the wrapper is `Copy` if it doesn't own the real-time model, the states have no
+    // pointer members and there is no trace hook
+    pub(crate) copy: bool,
+    // the wrapper receivers, `this` in the constructors and `self` in the methods
+    pub(crate) this: Receiver,
+    pub(crate) this_self: Receiver,
+    // the calls to the model from the constructors and from the methods
+    pub(crate) rtm_this: ModelCall,
+    pub(crate) rtm_self: ModelCall,
+    // the real-time model isn't built if the entry points don't take it
+    pub(crate) rtm_arg: bool,
+    // the model has global I/O
+    pub(crate) has_globals: bool,
+}
+impl Wrapper {
+    // The wrapper of the `model` with the `args` options, `header` is the model header contents
+    pub(crate) fn new(args: &Args, model: Model, header: String) -> Result<Self> {
+        let Model {
+            name: model,
+            inputs,
+            outputs,
+            states,
+            continuous,
+            zero_crossings,
+            params,
+            params_init,
+            checksum,
+            sample_time,
+            precision,
+            ..
+        } = model;
+        let control = args.control.clone();
+        let vis = args.vis.clone().unwrap_or_else(|| syn::parse_quote!(pub));
+        let wrapper = args.name.clone().unwrap_or_else(|| model.clone());
+        // the structures missing in the header are left out of the wrapper
+        let (has_inputs, has_outputs, has_states) =
+            (inputs.is_some(), outputs.is_some(), states.is_some());
+        let (inputs, outputs, states) = (
+            inputs.unwrap_or_default(),
+            outputs.unwrap_or_default(),
+            states.unwrap_or_default(),
+        );
+        // the signals sanitized into the same identifier would be duplicate fields or methods
+        for (list, kind) in [(&inputs, "inputs"), (&outputs, "outputs")] {
+            if let Some((first, second, ident)) = list.collision() {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    format!(
+                        "the {} `{}` and `{}` are both bound to the Rust identifier `{}`, \
+                         rename one of the signals in the Simulink model",
+                        kind, first, second, ident
+                    ),
+                ));
+            }
+        }
+        // the continuous states of continuous or hybrid models
+        let has_continuous = continuous.is_some();
+        let continuous = continuous.unwrap_or_default();
+        // the previous zero-crossings of the models with zero-crossing detection
+        let has_zero_crossings = zero_crossings.is_some();
+        let zero_crossings = zero_crossings.unwrap_or_default();
+        // only the states can be skipped, the inputs and outputs are the wrapper interface
+        for state in &args.skip_states {
+            let is = |list: &List| list.0.iter().any(|io| io.method() == *state);
+            let error = if is(&inputs) || is(&outputs) {
+                format!(
+                    "`{}` is an input or an output, only the states can be skipped",
+                    state
+                )
+            } else if !is(&states) {
+                format!("unknown state `{}`", state)
+            } else {
+                continue;
+            };
+            return Err(syn::Error::new(state.span(), error));
+        }
+
+        let rtm = Rtm::parse(&header, &model.to_string());
+        let initialize_fn = EntryPoint::parse(&header, &format!("{}_initialize", model));
+        let step_fn = EntryPoint::parse(&header, &format!("{}_step", model));
+        let tasks = parse_tasks(&header, &model.to_string());
+        let single_rate = tasks.is_empty();
+        let terminate_fn = EntryPoint::parse(&header, &format!("{}_terminate", model));
+
+        let no_std = !cfg!(feature = "std");
+        let trace = cfg!(feature = "trace") && !no_std && has_inputs && has_outputs;
+        let copy = rtm.is_copy() && !states.has_pointers() && !trace;
+
+        let (this, this_self) = (
+            Receiver::new(quote!(this), args.cache_aligned),
+            Receiver::new(quote!(self), args.cache_aligned),
+        );
+        // GRT with global I/O: the inputs, outputs and states the step function doesn't take
+        // as arguments are the `extern` globals of the model, e.g. `extern ExtU_<model>_T <model>_U;`,
+        // copied from and to the wrapper around each call to the model
+        let step_prototype = match tasks.first() {
+            Some(task) => EntryPoint::parse(&header, &format!("{}_step{}", model, task)),
+            None => EntryPoint::parse(&header, &format!("{}_step", model)),
+        };
+        let global = |present: bool, ty: &str| {
+            if !present || step_prototype.takes(&format!("{}_", ty)) {
+                return None;
+            }
+            Regex::new(&format!(r"\bextern\s+{}_{}_T\s+(\w+)\s*;", ty, model))
+                .unwrap()
+                .captures(&header)
+                .map(|caps| Ident::new(&caps[1], Span::call_site()))
+        };
+        let (global_inputs, global_outputs, global_states) = (
+            global(has_inputs, "ExtU"),
+            global(has_outputs, "ExtY"),
+            global(has_states && !matches!(rtm, Rtm::Allocated { .. }), "DW"),
+        );
+        let has_globals =
+            global_inputs.is_some() || global_outputs.is_some() || global_states.is_some();
+        let rtm_arg = step_prototype.takes("RT_MODEL_") || initialize_fn.takes("RT_MODEL_");
+        let global_io =
+            |receiver: &Receiver, (setup, ptr, post): (TokenStream, TokenStream, TokenStream)| {
+                let (inputs, outputs, states) =
+                    (receiver.inputs(), receiver.outputs(), receiver.states());
+                let setup = if rtm_arg { setup } else { quote!() };
+                let inputs_in = global_inputs
+                    .as_ref()
+                    .map(|global| quote!(unsafe { #global = #inputs };));
+                let states_in = global_states
+                    .as_ref()
+                    .map(|global| quote!(unsafe { #global = #states };));
+                let outputs_out = global_outputs
+                    .as_ref()
+                    .map(|global| quote!(#outputs = unsafe { #global };));
+                let states_out = global_states
+                    .as_ref()
+                    .map(|global| quote!(#states = unsafe { #global };));
+                ModelCall {
+                    setup: quote!(#setup #inputs_in #states_in),
+                    ptr,
+                    post: quote!(#post #outputs_out #states_out),
+                }
+            };
+        let rtm_this = global_io(&this, rtm.quote(&model, &this));
+        let rtm_self = global_io(&this_self, rtm.quote(&model, &this_self));
+
+        Ok(Self {
+            model,
+            control,
+            wrapper,
+            vis,
+            catch_unwind: args.catch_unwind,
+            output_range: args.output_range,
+            deadband: args.deadband,
+            interior_mutability: args.interior_mutability,
+            init: args.init.clone(),
+            cache_aligned: args.cache_aligned,
+            expose_states: args.expose_states,
+            skip_states: args.skip_states.clone(),
+            inputs,
+            outputs,
+            states,
+            continuous,
+            zero_crossings,
+            params,
+            params_init,
+            has_inputs,
+            has_outputs,
+            has_states,
+            has_continuous,
+            has_zero_crossings,
+            checksum,
+            sample_time,
+            precision,
+            header,
+            rtm,
+            initialize_fn,
+            step_fn,
+            terminate_fn,
+            tasks,
+            single_rate,
+            no_std,
+            trace,
+            copy,
+            this,
+            this_self,
+            rtm_this,
+            rtm_self,
+            rtm_arg,
+            has_globals,
+        })
+    }
+    // The `code` if the `std` feature is enabled
+    pub(crate) fn with_std(&self, code: TokenStream) -> TokenStream {
+        if self.no_std {
+            quote!()
+        } else {
+            code
+        }
+    }
+    // The wrapper states place, if the model has states
+    pub(crate) fn states_place(&self, receiver: &Receiver) -> Option<TokenStream> {
+        self.rtm.states(receiver).filter(|_| self.has_states)
+    }
+    // The type of a wrapper structure field, cache line aligned with `cache_aligned`
+    fn aligned(&self, ty: TokenStream) -> TokenStream {
+        let control = &self.control;
+        if self.cache_aligned {
+            quote!([<#control Aligned>]<#ty>)
+        } else {
+            ty
+        }
+    }
+    // The alias of the wrapper named after the macro first argument, if the wrapper is renamed
+    pub(crate) fn alias(&self) -> TokenStream {
+        let Self {
+            control,
+            wrapper,
+            vis,
+            ..
+        } = self;
+        if wrapper == control {
+            quote!()
+        } else {
+            quote!(#vis type #control = #wrapper;)
+        }
+    }
+    // The wrapper structure, its `Default` and `Drop` implementations and the `Send` and
+    // `Sync` implementations of the wrappers of plain data
+    pub(crate) fn structure(&self) -> TokenStream {
+        let Self {
+            model,
+            wrapper,
+            vis,
+            rtm,
+            ..
+        } = self;
+        let (inputs_ty, outputs_ty, states_ty) = (
+            self.aligned(quote!([<ExtU_ #model _T>])),
+            self.aligned(quote!([<ExtY_ #model _T>])),
+            self.aligned(quote!([<DW_ #model _T>])),
+        );
+        let (inputs_field, inputs_default) = if self.has_inputs {
+            (
+                quote! {
+                    // Inputs Simulink structure
+                    pub inputs: #inputs_ty,
+                },
+                quote!(inputs: Default::default(),),
+            )
+        } else {
+            Default::default()
+        };
+        let (outputs_field, outputs_default) = if self.has_outputs {
+            (
+                quote! {
+                    // Outputs Simulink structure
+                    pub outputs: #outputs_ty,
+                },
+                quote!(outputs: Default::default(),),
+            )
+        } else {
+            Default::default()
+        };
+        let (params_field, params_default) = self.parameters_field();
+        let (continuous_field, continuous_default) = self.continuous_field();
+        let (zero_crossings_field, zero_crossings_default) = self.zero_crossings_field();
+        let (range_field, range_default) = self.output_range_field();
+        let (trace_field, trace_default) = self.trace_field();
+        let (rtm_field, rtm_default) = rtm.storage(
+            model,
+            wrapper,
+            self.has_states.then_some(&states_ty),
+            self.expose_states,
+            &quote!(#inputs_default #outputs_default #range_default #trace_default #params_default #continuous_default #zero_crossings_default),
+        );
+        // a copy of the states pointer members would alias the buffers of the original wrapper
+        let derive = if self.copy {
+            quote!(#[derive(Debug, Clone, Copy, Default)])
+        } else if rtm.is_copy() {
+            quote!(#[derive(Debug, Default)])
+        } else {
+            quote!()
+        };
+        let serde_derive = self.serde_derive();
+        // the wrapper holds only plain data if the real-time model isn't owned, the model has
+        // no global I/O and the states and parameters have no pointer members
+        let send_sync = if rtm.is_copy()
+            && !self.has_globals
+            && !self.states.has_pointers()
+            && !self.continuous.has_pointers()
+            && !self.params.has_pointers()
+        {
+            quote! {
+                unsafe impl Send for #wrapper {}
+                unsafe impl Sync for #wrapper {}
+            }
+        } else {
+            quote!()
+        };
+        quote! {
+            /// Simulink controller wrapper
+            #derive
+            #serde_derive
+            #vis struct #wrapper {
+                #inputs_field
+                #outputs_field
+                #params_field
+                #continuous_field
+                #zero_crossings_field
+                #rtm_field
+                #range_field
+                #trace_field
+            }
+            #rtm_default
+            #send_sync
+        }
+    }
+    // The cache line aligned storage of the Simulink structures
+    pub(crate) fn aligned_storage(&self) -> TokenStream {
+        if !self.cache_aligned {
+            return quote!();
+        }
+        let control = &self.control;
+        let aligned_serde = if cfg!(feature = "serde") {
+            quote! {
+                #[derive(::simulink_rs::serde::Serialize, ::simulink_rs::serde::Deserialize)]
+                #[serde(crate = "::simulink_rs::serde", transparent)]
+            }
+        } else {
+            quote!()
+        };
+        quote! {
+            /// Cache line aligned storage of a Simulink structure
+            ///
+            /// Dereferences to the Simulink structure
+            #[repr(C, align(64))]
+            #[derive(Debug, Clone, Copy, Default)]
+            #aligned_serde
+            pub struct [<#control Aligned>]<T>(pub T);
+            impl<T> core::ops::Deref for [<#control Aligned>]<T> {
+                type Target = T;
+                fn deref(&self) -> &T {
+                    &self.0
+                }
+            }
+            impl<T> core::ops::DerefMut for [<#control Aligned>]<T> {
+                fn deref_mut(&mut self) -> &mut T {
+                    &mut self.0
+                }
+            }
+        }
+    }
+    // The `Default` implementations of the Simulink structures
+    pub(crate) fn structures_default(&self) -> TokenStream {
+        let model = &self.model;
+        let structure_default = |present: bool, ty: &str, list: &List| {
+            if present {
+                let c_type = format!("{}_{}_T", ty, model);
+                let var = list.quote(&c_type, None);
+                let pointers = list.null_pointers();
+                let ty = Ident::new(&c_type, Span::call_site());
+                quote! {
+                    impl Default for #ty {
+                        fn default() -> Self {
+                            Self { #var #pointers }
+                        }
+                    }
+                }
+            } else {
+                quote!()
+            }
+        };
+        let structures_default = [
+            structure_default(self.has_inputs, "ExtU", &self.inputs),
+            structure_default(self.has_outputs, "ExtY", &self.outputs),
+            structure_default(self.has_states, "DW", &self.states),
+            structure_default(self.has_continuous, "X", &self.continuous),
+            structure_default(self.has_zero_crossings, "PrevZCX", &self.zero_crossings),
+        ];
+        quote!(#(#structures_default)*)
+    }
+    // The continuous states field and its default value
+    fn continuous_field(&self) -> (TokenStream, TokenStream) {
+        if !self.has_continuous {
+            return Default::default();
+        }
+        let model = &self.model;
+        let vis = self.expose_states.then(|| quote!(pub));
+        (
+            quote! {
+                // Continuous states Simulink structure
+                #vis states_continuous: [<X_ #model _T>],
+            },
+            quote!(states_continuous: Default::default(),),
+        )
+    }
+    // The previous zero-crossings field and its default value
+    fn zero_crossings_field(&self) -> (TokenStream, TokenStream) {
+        if !self.has_zero_crossings {
+            return Default::default();
+        }
+        let model = &self.model;
+        let vis = self.expose_states.then(|| quote!(pub));
+        (
+            quote! {
+                // Previous zero-crossings Simulink structure
+                #vis zero_crossings: [<PrevZCX_ #model _T>],
+            },
+            quote!(zero_crossings: Default::default(),),
+        )
+    }
+    // The parameters field and its default value
+    fn parameters_field(&self) -> (TokenStream, TokenStream) {
+        if self.params.0.is_empty() {
+            return Default::default();
+        }
+        let model = &self.model;
+        (
+            quote! {
+                // Parameters Simulink structure
+                pub parameters: [<P_ #model _T>],
+            },
+            quote!(parameters: Default::default(),),
+        )
+    }
+    // The parameters structure implementations and the parameters setter
+    pub(crate) fn parameters(&self) -> (TokenStream, TokenStream) {
+        if self.params.0.is_empty() {
+            return Default::default();
+        }
+        let Self { model, params, .. } = self;
+        // the values of the parameters initializer if they match the parameters
+        let init = self.params_init.as_ref().filter(|values| {
+            let len: usize = params.0.iter().map(IO::len).sum();
+            let matched = values.len() == len;
+            if !matched {
+                diagnostic!(
+                    "| ! {} values in the parameters initializer for {} parameters, zero parameters",
+                    values.len(),
+                    len
+                );
+            }
+            matched
+        });
+        let var_p = params.quote(&format!("P_{}_T", model), init.map(Vec::as_slice));
+        let pointers_p = params.null_pointers();
+        let params_len: usize = params.0.iter().map(IO::len).sum();
+        let assignments = params
+            .elements(quote!(parameters))
+            .into_iter()
+            .enumerate()
+            .map(|(i, (param, ty))| {
+                let value = ty.of_float(Primitive::F64, quote!(flat[#i]));
+                quote!(#param = #value;)
+            });
+        let serde = params.serde(&format!("P_{}_T", model));
+        (
+            quote! {
+                impl Default for [<P_ #model _T>] {
+                    fn default() -> Self {
+                        Self { #var_p #pointers_p }
+                    }
+                }
+                /// The parameters from a flat slice
+                ///
+                /// The slice holds the parameters in the order of the Simulink structure,
+                /// arrays element-wise in row-major order
+                impl TryFrom<&[f64]> for [<P_ #model _T>] {
+                    type Error = ::simulink_rs::SimulinkError;
+                    fn try_from(flat: &[f64]) -> Result<Self, Self::Error> {
+                        if flat.len() != #params_len {
+                            return Err(::simulink_rs::SimulinkError::WrongParametersLength {
+                                expected: #params_len,
+                                got: flat.len(),
+                            });
+                        }
+                        let mut parameters = Self::default();
+                        #(#assignments)*
+                        Ok(parameters)
+                    }
+                }
+                #serde
+            },
+            quote! {
+                /// Number of parameters elements, arrays element-wise
+                pub const PARAMETER_LEN: usize = #params_len;
+                /// Sets the parameters from a flat slice
+                ///
+                /// The slice holds the parameters in the order of the Simulink structure,
+                /// arrays element-wise in row-major order, as the `TryFrom<&[f64]>` conversion
+                /// of the parameters.
+                /// The parameters are read by the next step, the parameters the model only
+                /// reads in its initialization take effect after a [reset](Self::reset)
+                ///
+                /// # Errors
+                ///
+                /// Returns [SimulinkError::WrongParametersLength](::simulink_rs::SimulinkError::WrongParametersLength)
+                /// if `flat` length is not [PARAMETER_LEN](Self::PARAMETER_LEN),
+                /// the parameters are then left unchanged
+                pub fn set_parameters(&mut self, flat: &[f64]) -> Result<(), ::simulink_rs::SimulinkError> {
+                    self.parameters = flat.try_into()?;
+                    Ok(())
+                }
+            },
+        )
+    }
+    // The inputs and outputs layout constants
+    pub(crate) fn layout_constants(&self) -> TokenStream {
+        let Self {
+            inputs, outputs, ..
+        } = self;
+        let io_fingerprint = fnv1a(&format!("{}|{}", inputs.layout(), outputs.layout()));
+        let (input_len, output_len) = (inputs.names().len(), outputs.names().len());
+        let (input_layout, output_layout) = (inputs.layout_table(), outputs.layout_table());
+        quote! {
+            /// Fingerprint of the inputs and outputs layout
+            ///
+            /// FNV-1a hash of the names, C types and sizes of the inputs and outputs
+            pub const IO_FINGERPRINT: u32 = #io_fingerprint;
+            /// Number of inputs elements, arrays element-wise
+            pub const INPUT_LEN: usize = #input_len;
+            /// Number of outputs elements, arrays element-wise
+            pub const OUTPUT_LEN: usize = #output_len;
+            /// Simulink names and numbers of elements of the inputs, in the structure order
+            pub const INPUT_LAYOUT: &'static [(&'static str, usize)] = #input_layout;
+            /// Simulink names and numbers of elements of the outputs, in the structure order
+            pub const OUTPUT_LAYOUT: &'static [(&'static str, usize)] = #output_layout;
+        }
+    }
+    // The raw pointers to the wrapper structures and the real-time model set up as for a
+    // step, for calling the other C functions of the model
+    pub(crate) fn raw_pointers(&self) -> TokenStream {
+        let Self {
+            model,
+            this_self,
+            rtm,
+            ..
+        } = self;
+        let inputs_raw = self.has_inputs.then(|| {
+            let inputs = this_self.inputs();
+            quote! {
+                /// Returns a raw pointer to the inputs Simulink structure
+                ///
+                /// The pointer is valid as long as the wrapper isn't moved or dropped
+                pub fn inputs_raw(&mut self) -> *mut [<ExtU_ #model _T>] {
+                    &mut #inputs as *mut _
+                }
+            }
+        });
+        let outputs_raw = self.has_outputs.then(|| {
+            let outputs = this_self.outputs();
+            quote! {
+                /// Returns a raw pointer to the outputs Simulink structure
+                ///
+                /// The pointer is valid as long as the wrapper isn't moved or dropped
+                pub fn outputs_raw(&mut self) -> *mut [<ExtY_ #model _T>] {
+                    &mut #outputs as *mut _
+                }
+            }
+        });
+        let rtm_raw = self.rtm_arg.then(|| {
+            let (setup, ptr, _) = rtm.quote(model, this_self);
+            match rtm {
+                Rtm::Transient { .. } => quote! {
+                    /// Returns the real-time model structure set up as for a step
+                    ///
+                    /// The structure points to the wrapper states, and inputs, outputs and parameters
+                    /// if it has the members, it is valid as long as the wrapper isn't moved or dropped
+                    #[allow(unused_mut)]
+                    pub fn rtm_raw(&mut self) -> [<RT_MODEL_ #model _T>] {
+                        #setup
+                        data
+                    }
+                },
+                _ => quote! {
+                    /// Returns a raw pointer to the real-time model structure set up as for a step
+                    ///
+                    /// The pointer is valid as long as the wrapper isn't dropped, the outputs of an
+                    /// allocated real-time model aren't copied back to the wrapper
+                    pub fn rtm_raw(&mut self) -> *mut [<RT_MODEL_ #model _T>] {
+                        #setup
+                        #ptr
+                    }
+                },
+            }
+        });
+        quote! {
+            #inputs_raw
+            #outputs_raw
+            #rtm_raw
+        }
+    }
+    // The constructors, with the default parameters if the model has parameters
+    pub(crate) fn constructors(&self) -> TokenStream {
+        let Self { model, this, .. } = self;
+        let ModelCall { setup, ptr, post } = &self.rtm_this;
+        let initialize_args = self.initialize_fn.quote(ptr, this);
+        // the model initialization, after the parameters are set if the model has any
+        let initialization = quote! {
+            #setup
+            unsafe {
+                [< #model _initialize>](#initialize_args)
+            }
+            #post
+            this
+        };
+        if self.params.0.is_empty() {
+            return quote! {
+                /// Creates a new controller
+                pub fn new() -> Self {
+                    let mut this: Self = Default::default();
+                    #initialization
+                }
+            };
+        }
+        // the default parameters of the model, e.g. `extern P_<model>_T <model>_P;`,
+        // initialized in the model data C file
+        let (default_params, doc) = match Regex::new(&format!(
+            r"\bextern\s+P_{}_T\s+(\w+)\s*;",
+            model
+        ))
+        .unwrap()
+        .captures(&self.header)
+        {
+            Some(caps) => {
+                let global = Ident::new(&caps[1], Span::call_site());
+                (
+                    quote!(unsafe { #global }),
+                    format!(
+                        " Creates a new controller\n\n The parameters are the default values of `{}`",
+                        global
+                    ),
+                )
+            }
+            None => (
+                quote!(Default::default()),
+                " Creates a new controller\n\n The parameters are set to zero".to_string(),
+            ),
+        };
+        quote! {
+            #[doc = #doc]
+            pub fn new() -> Self {
+                Self::with_parameters(#default_params)
+            }
+            /// Creates a new controller with the `parameters`, set before the model initialization
+            pub fn with_parameters(parameters: [<P_ #model _T>]) -> Self {
+                let mut this: Self = Default::default();
+                this.parameters = parameters;
+                #initialization
+            }
+        }
+    }
+    // The inputs builder and the constructor with the inputs
+    pub(crate) fn inputs_builder(&self) -> (TokenStream, TokenStream) {
+        if !self.has_inputs {
+            return Default::default();
+        }
+        let Self { model, control, .. } = self;
+        let setters = self.inputs.builder_setters();
+        let this_inputs = self.this.inputs();
+        (
+            quote! {
+                /// Builder of the inputs, starting from the default inputs
+                #[derive(Debug, Clone, Copy, Default)]
+                pub struct [<#control InputsBuilder>] {
+                    inputs: [<ExtU_ #model _T>],
+                }
+                impl [<#control InputsBuilder>] {
+                    #setters
+                    /// Returns the inputs
+                    pub fn build(self) -> [<ExtU_ #model _T>] {
+                        self.inputs
+                    }
+                }
+                impl [<ExtU_ #model _T>] {
+                    /// Returns the builder of the inputs, starting from the default inputs
+                    pub fn builder() -> [<#control InputsBuilder>] {
+                        Default::default()
+                    }
+                }
+            },
+            quote! {
+                /// Creates a new controller with the `inputs`, set after the model initialization
+                pub fn with_inputs(inputs: [<ExtU_ #model _T>]) -> Self {
+                    let mut this = Self::new();
+                    #this_inputs = inputs;
+                    this
+                }
+            },
+        )
+    }
+    // The constructor with the states set to the initial conditions of the `init` file
+    pub(crate) fn new_from_ic(&self) -> Result<TokenStream> {
+        match (&self.init, self.states_place(&self.this)) {
+            (Some(init), Some(states)) => {
+                let assignments = parse_ic(init, &self.states, &states)?;
+                let doc = format!(
+                    " Creates a new controller with the states set to the initial conditions in `{}`",
+                    init.value()
+                );
+                Ok(quote! {
+                    #[doc = #doc]
+                    ///
+                    /// The states are set after the model initialization
+                    pub fn new_from_ic() -> Self {
+                        let mut this = Self::new();
+                        unsafe {
+                            #assignments
+                        }
+                        this
+                    }
+                })
+            }
+            (Some(init), None) => Err(syn::Error::new(
+                init.span(),
+                "the model states are not accessible",
+            )),
+            _ => Ok(quote!()),
+        }
+    }
+    // The step methods, the tasks step methods of a multi-rate model
+    pub(crate) fn steps(&self) -> TokenStream {
+        let Self {
+            model,
+            this_self,
+            header,
+            tasks,
+            ..
+        } = self;
+        let ModelCall { setup, ptr, post } = &self.rtm_self;
+        let (range_update, trace_update) = (self.output_range_update(), self.trace_update());
+        if self.single_rate {
+            let step_args = self.step_fn.quote(ptr, this_self);
+            // the real-time model is set up once for all the steps, unless the outputs range
+            // or the trace hook borrow the wrapper between the steps
+            let steps = |n: TokenStream| {
+                if self.output_range || self.trace {
+                    quote! {
+                        for _ in 0..#n {
+                            #setup
+                            unsafe {
+                                [<#model _step>](#step_args)
+                            }
+                            #post
+                            #range_update
+                            #trace_update
+                        }
+                    }
+                } else {
+                    quote! {
+                        #setup
+                        for _ in 0..#n {
+                            unsafe {
+                                [<#model _step>](#step_args)
+                            }
+                        }
+                        #post
+                    }
+                }
+            };
+            let (step_const, step_for) = (steps(quote!(N)), steps(quote!(n)));
+            return quote! {
+                /// Steps the controller
+                pub fn step(&mut self) {
+                    self.step_for(1)
+                }
+                /// Steps the controller `N` times
+                ///
+                /// The number of steps is known at compile time allowing the loop to be unrolled
+                #[inline]
+                pub fn step_const<const N: usize>(&mut self) {
+                    #step_const
+                }
+                /// Steps the controller `n` times
+                ///
+                /// The inputs are kept for all the steps
+                #[inline]
+                pub fn step_for(&mut self, n: usize) {
+                    #step_for
+                }
+            };
+        }
+        let (arms, steps): (Vec<_>, Vec<_>) = tasks
+            .iter()
+            .map(|task| {
+                let method = Ident::new(&format!("step{}", task), Span::call_site());
+                let function = Ident::new(&format!("{}_step{}", model, task), Span::call_site());
+                let args = EntryPoint::parse(header, &function.to_string()).quote(ptr, this_self);
+                let doc = format!(" Steps the task {} of the controller", task);
+                (
+                    quote!(#task => self.#method(),),
+                    quote! {
+                        #[doc = #doc]
+                        pub fn #method(&mut self) {
+                            #setup
+                            unsafe {
+                                #function(#args)
+                            }
+                            #post
+                            #range_update
+                            #trace_update
+                        }
+                    },
+                )
+            })
+            .unzip();
+        let task_periods = match parse_task_periods(header, &model.to_string(), tasks) {
+            Some(periods) => {
+                let (periods, offsets): (Vec<_>, Vec<_>) = periods.into_iter().unzip();
+                let methods = tasks
+                    .iter()
+                    .map(|task| Ident::new(&format!("step{}", task), Span::call_site()));
+                quote! {
+                    /// Sample periods of the tasks in seconds, in the order of the tasks
+                    pub const TASK_PERIODS: &[f64] = &[#(#periods),*];
+                    /// Sample time offsets of the tasks in seconds, in the order of the tasks
+                    pub const TASK_OFFSETS: &[f64] = &[#(#offsets),*];
+                    /// Steps the tasks due at the elapsed time `t` in seconds
+                    ///
+                    /// A task is due if `t` minus the task offset is a multiple of the task period,
+                    /// the tasks are stepped in their order
+                    pub fn step_due(&mut self, t: f64) {
+                        #(
+                            let ticks = (t - #offsets) / #periods;
+                            let error = ticks - (ticks + 0.5) as i64 as f64;
+                            if ticks > -1e-6 && error > -1e-6 && error < 1e-6 {
+                                self.#methods();
+                            }
+                        )*
+                    }
+                }
+            }
+            None => quote!(),
+        };
+        let n_task = tasks.len();
+        quote! {
+            /// Steps the task `task_id` of the multi-rate controller
+            ///
+            /// # Errors
+            ///
+            /// Returns [SimulinkError::IndexOutOfBounds](::simulink_rs::SimulinkError::IndexOutOfBounds)
+            /// if the model has no task `task_id`
+            pub fn step(&mut self, task_id: usize) -> Result<(), ::simulink_rs::SimulinkError> {
+                match task_id {
+                    #(#arms)*
+                    _ => {
+                        return Err(::simulink_rs::SimulinkError::IndexOutOfBounds {
+                            field: "task_id",
+                            index: task_id,
+                            len: #n_task,
+                        })
+                    }
+                }
+                Ok(())
+            }
+            #(#steps)*
+            #task_periods
+        }
+    }
+    // The in place reset
+    pub(crate) fn reset(&self) -> TokenStream {
+        let Self {
+            model, this_self, ..
+        } = self;
+        let ModelCall { setup, ptr, post } = &self.rtm_self;
+        let reset_args = self.initialize_fn.quote(ptr, this_self);
+        // the allocated model states live in the real-time model structure
+        let states = match self.states_place(this_self) {
+            Some(states) if matches!(self.rtm, Rtm::Allocated { .. }) => {
+                quote!(unsafe { #states = Default::default() };)
+            }
+            Some(states) => quote!(#states = Default::default();),
+            None => quote!(),
+        };
+        let range = if self.output_range {
+            quote!(self.output_range = None;)
+        } else {
+            quote!()
+        };
+        let (self_inputs, self_outputs) = (this_self.inputs(), this_self.outputs());
+        let inputs = self
+            .has_inputs
+            .then(|| quote!(#self_inputs = Default::default();));
+        let outputs = self
+            .has_outputs
+            .then(|| quote!(#self_outputs = Default::default();));
+        let continuous = self
+            .has_continuous
+            .then(|| quote!(self.states_continuous = Default::default();));
+        let zero_crossings = self
+            .has_zero_crossings
+            .then(|| quote!(self.zero_crossings = Default::default();));
+        quote! {
+            /// Resets the controller in place
+            ///
+            /// The inputs, outputs and states are set to their defaults and the model is initialized
+            /// again, as in [new](Self::new), the parameters are left unchanged
+            pub fn reset(&mut self) {
+                #inputs
+                #outputs
+                #states
+                #continuous
+                #zero_crossings
+                #range
+                #setup
+                unsafe {
+                    [<#model _initialize>](#reset_args)
+                }
+                #post
+            }
+        }
+    }
+    // The generic interface of the single-rate models
+    pub(crate) fn simulink_model(&self) -> TokenStream {
+        if !self.single_rate {
+            return quote!();
+        }
+        let wrapper = &self.wrapper;
+        quote! {
+            impl ::simulink_rs::SimulinkModel for #wrapper {
+                const INPUT_LEN: usize = Self::INPUT_LEN;
+                const OUTPUT_LEN: usize = Self::OUTPUT_LEN;
+                fn step(&mut self) {
+                    Self::step(self)
+                }
+                fn reset(&mut self) {
+                    Self::reset(self)
+                }
+            }
+        }
+    }
+    // The continuous states derivatives of continuous or hybrid models
+    pub(crate) fn derivatives(&self) -> TokenStream {
+        let model = &self.model;
+        let derivatives = EntryPoint::parse(&self.header, &format!("{}_derivatives", model));
+        if !self.has_continuous || derivatives.0.is_none() {
+            return quote!();
+        }
+        let ModelCall { setup, ptr, post } = &self.rtm_self;
+        let args = derivatives.quote(ptr, &self.this_self);
+        quote! {
+            /// Computes the derivatives of the continuous states
+            pub fn derivatives(&mut self) {
+                #setup
+                unsafe {
+                    [<#model _derivatives>](#args)
+                }
+                #post
+            }
+        }
+    }
+    // The states accessors, with `expose_states`
+    pub(crate) fn states_accessors(&self) -> TokenStream {
+        let model = &self.model;
+        match self
+            .states_place(&self.this_self)
+            .filter(|_| self.expose_states)
+        {
+            Some(states) => {
+                // the allocated model states live in the real-time model structure
+                let (states_ref, states_mut) = if matches!(self.rtm, Rtm::Allocated { .. }) {
+                    (quote!(unsafe { &#states }), quote!(unsafe { &mut #states }))
+                } else {
+                    (quote!(&#states), quote!(&mut #states))
+                };
+                quote! {
+                    /// Returns a reference to the states
+                    pub fn states(&self) -> &[<DW_ #model _T>] {
+                        #states_ref
+                    }
+                    /// Returns a mutable reference to the states
+                    pub fn states_mut(&mut self) -> &mut [<DW_ #model _T>] {
+                        #states_mut
+                    }
+                }
+            }
+            None => quote!(),
+        }
+    }
+    // The model termination, the allocated model is terminated when the wrapper is dropped
+    pub(crate) fn terminate(&self) -> TokenStream {
+        let model = &self.model;
+        match (&self.terminate_fn.0, &self.rtm) {
+            (Some(_), Rtm::Transient { .. } | Rtm::SimStruct { .. }) => {
+                let ModelCall { setup, ptr, post } = &self.rtm_self;
+                let terminate_args = self.terminate_fn.quote(ptr, &self.this_self);
+                quote! {
+                    /// Terminates the controller
+                    ///
+                    /// Releases the resources the model allocated during the initialization
+                    pub fn terminate(&mut self) {
+                        #setup
+                        unsafe {
+                            [<#model _terminate>](#terminate_args)
+                        }
+                        #post
+                    }
+                }
+            }
+            _ => quote!(),
+        }
+    }
+    // The inputs setters and the outputs getters
+    pub(crate) fn accessors(&self) -> TokenStream {
+        let (getters, setters) = (self.outputs.getters(), self.inputs.setters());
+        quote! {
+            #setters
+            #getters
+        }
+    }
+    // The iterator over the inputs and outputs values
+    pub(crate) fn signals(&self) -> TokenStream {
+        let Self {
+            inputs, outputs, ..
+        } = self;
+        let names: Vec<_> = inputs.names().into_iter().chain(outputs.names()).collect();
+        let arms: Vec<_> = inputs
+            .elements(quote!(self.inputs))
+            .into_iter()
+            .chain(outputs.elements(quote!(self.outputs)))
+            .enumerate()
+            .map(|(i, (value, ty))| {
+                let (name, value) = (&names[i], ty.to_f64(value));
+                quote!(#i => (#name, #value))
+            })
+            .collect();
+        let n = arms.len();
+        quote! {
+            /// Returns an iterator over the names and the values of the inputs followed by the outputs
+            ///
+            /// Arrays are expanded into elements named `name[i]`
+            pub fn signals(&self) -> impl Iterator<Item = (&'static str, f64)> + '_ {
+                (0..#n).map(move |i| match i {
+                    #(#arms,)*
+                    _ => unreachable!(),
+                })
+            }
+        }
+    }
+    // The model fundamental sample time
+    pub(crate) fn sample_time(&self) -> TokenStream {
+        match self.sample_time {
+            Some(ts) => quote! {
+                /// Model fundamental sample time in seconds
+                pub const SAMPLE_TIME: f64 = #ts;
+                /// Returns the model fundamental sample time in seconds
+                pub fn sample_time() -> Option<f64> {
+                    Some(Self::SAMPLE_TIME)
+                }
+            },
+            None => quote! {
+                /// Returns the model fundamental sample time in seconds,
+                /// `None` as it isn't found in the Simulink files
+                pub fn sample_time() -> Option<f64> {
+                    None
+                }
+            },
+        }
+    }
+    // The flat conversions of the inputs and outputs structures, of the `real_T` type
+    pub(crate) fn flat_conversions(&self) -> TokenStream {
+        let Self {
+            model, precision, ..
+        } = self;
+        let float = precision.ty();
+        let flatten = |present: bool, list: &List, ty: TokenStream| {
+            if present && !self.no_std {
+                let values: Vec<_> = list
+                    .elements(quote!(value))
+                    .into_iter()
+                    .map(|(value, ty)| ty.to_float(*precision, value))
+                    .collect();
+                quote! {
+                    impl From<&#ty> for Vec<#float> {
+                        fn from(value: &#ty) -> Self {
+                            vec![#(#values),*]
+                        }
+                    }
+                }
+            } else {
+                quote!()
+            }
+        };
+        let flat_conversions = [
+            flatten(self.has_inputs, &self.inputs, quote!([<ExtU_ #model _T>])),
+            flatten(self.has_outputs, &self.outputs, quote!([<ExtY_ #model _T>])),
+        ];
+        quote!(#(#flat_conversions)*)
+    }
+    // The comparisons of the inputs and outputs structures, exact, within a relative
+    // tolerance with `approx_eq` and the `approx` traits with `approx`
+    pub(crate) fn comparisons(&self) -> TokenStream {
+        let Self {
+            model,
+            inputs,
+            outputs,
+            has_inputs,
+            has_outputs,
+            ..
+        } = self;
+        let partial_eq = [
+            has_inputs.then(|| inputs.partial_eq(quote!([<ExtU_ #model _T>]))),
+            has_outputs.then(|| outputs.partial_eq(quote!([<ExtY_ #model _T>]))),
+        ];
+        let approx = cfg!(feature = "approx_eq");
+        let approx_eq = [
+            (approx && *has_inputs).then(|| inputs.approx_eq(quote!([<ExtU_ #model _T>]))),
+            (approx && *has_outputs).then(|| outputs.approx_eq(quote!([<ExtY_ #model _T>]))),
+        ];
+        let abs_diff = cfg!(feature = "approx");
+        let abs_diff_eq = [
+            (abs_diff && *has_inputs).then(|| inputs.abs_diff_eq(quote!([<ExtU_ #model _T>]))),
+            (abs_diff && *has_outputs).then(|| outputs.abs_diff_eq(quote!([<ExtY_ #model _T>]))),
+        ];
+        quote! {
+            #(#partial_eq)*
+            #(#approx_eq)*
+            #(#abs_diff_eq)*
+        }
+    }
+    // The inputs and outputs structures indexed by the variables enumerations
+    pub(crate) fn signal_index(&self) -> TokenStream {
+        let Self {
+            model,
+            control,
+            vis,
+            ..
+        } = self;
+        let signal_index = [
+            self.has_inputs.then(|| {
+                self.inputs.signal_index(
+                    quote!([<ExtU_ #model _T>]),
+                    quote!([<#control Input>]),
+                    quote!([<#control InputArray>]),
+                    vis,
+                )
+            }),
+            self.has_outputs.then(|| {
+                self.outputs.signal_index(
+                    quote!([<ExtY_ #model _T>]),
+                    quote!([<#control Output>]),
+                    quote!([<#control OutputArray>]),
+                    vis,
+                )
+            }),
+        ];
+        quote!(#(#signal_index)*)
+    }
+    // The parsed variables against the memory layout of the Simulink structures and the
+    // defaults of the plain-data structures against the all-zero C structures
+    pub(crate) fn layout_assertions(&self) -> TokenStream {
+        let Self { model, control, .. } = self;
+        let structures = [
+            (self.has_inputs, &self.inputs, "ExtU"),
+            (self.has_outputs, &self.outputs, "ExtY"),
+            (self.has_states, &self.states, "DW"),
+            (self.has_continuous, &self.continuous, "X"),
+            (self.has_zero_crossings, &self.zero_crossings, "PrevZCX"),
+            (!self.params.0.is_empty(), &self.params, "P"),
+        ];
+        let layouts = structures
+            .iter()
+            .filter(|(present, ..)| *present)
+            .map(|(_, list, ty)| {
+                let c_type = format!("{}_{}_T", ty, model);
+                let ty = Ident::new(&c_type, Span::call_site());
+                list.layout_assertions(quote!(#ty), &c_type)
+            });
+        // the parameters default to their initializer values and are left out
+        let zeroed_defaults = {
+            let assertions: Vec<_> = structures
+                .iter()
+                .filter(|(present, list, ty)| *present && *ty != "P" && !list.has_pointers())
+                .map(|(_, list, ty)| {
+                    let c_type = format!("{}_{}_T", ty, model);
+                    let ty = Ident::new(&c_type, Span::call_site());
+                    list.zeroed_assertions(quote!(#ty), &c_type)
+                })
+                .collect();
+            (!assertions.is_empty()).then(|| {
+                quote! {
+                    #[cfg(test)]
+                    #[test]
+                    #[doc(hidden)]
+                    fn [<#control:snake _defaults_are_zeroed>]() {
+                        #(#assertions)*
+                    }
+                }
+            })
+        };
+        quote! {
+            #(#layouts)*
+            #zeroed_defaults
+        }
+    }
+    // The inputs length error and the inputs setter from a flat slice
+    pub(crate) fn set_inputs(&self) -> (TokenStream, TokenStream) {
+        if !self.has_inputs {
+            return Default::default();
+        }
+        let Self {
+            control, precision, ..
+        } = self;
+        let float = precision.ty();
+        let assignments = self
+            .inputs
+            .elements(quote!(self.inputs))
+            .into_iter()
+            .enumerate()
+            .map(|(i, (input, ty))| {
+                let value = ty.of_float(*precision, quote!(flat[#i]));
+                quote!(#input = #value;)
+            });
+        (
+            quote! {
+                /// Mismatch between the length of a flat slice and the number of inputs elements
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                pub struct [<#control LenError>] {
+                    /// The number of inputs elements
+                    pub expected: usize,
+                    /// The slice length
+                    pub found: usize,
+                }
+                impl core::fmt::Display for [<#control LenError>] {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        write!(
+                            f,
+                            "expected {} inputs values, found {}",
+                            self.expected, self.found
+                        )
+                    }
+                }
+                impl core::error::Error for [<#control LenError>] {}
+            },
+            quote! {
+                /// Sets the inputs from a flat slice
+                ///
+                /// The slice holds the inputs in the order of the Simulink structure,
+                /// arrays element-wise, as the `Vec` conversion of the inputs
+                pub fn set_inputs(&mut self, flat: &[#float]) -> Result<(), [<#control LenError>]> {
+                    if flat.len() != Self::INPUT_LEN {
+                        return Err([<#control LenError>] {
+                            expected: Self::INPUT_LEN,
+                            found: flat.len(),
+                        });
+                    }
+                    #(#assignments)*
+                    Ok(())
+                }
+            },
+        )
+    }
+    // The `Display` implementation of the wrapper
+    pub(crate) fn display(&self) -> TokenStream {
+        let display = [
+            (&self.inputs, quote!(inputs)),
+            (&self.outputs, quote!(outputs)),
+        ]
+        .into_iter()
+        .flat_map(|(list, field)| {
+            list.0.iter().map(move |io| {
+                let (name, var) = (&io.name, io.var());
+                let format = if io.is_array() { "{}: {:?}" } else { "{}: {}" };
+                quote!(writeln!(f, #format, #name, self.#field.#var)?;)
+            })
+        });
+        let wrapper = &self.wrapper;
+        quote! {
+            impl core::fmt::Display for #wrapper {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    #(#display)*
+                    Ok(())
+                }
+            }
+        }
+    }
+    // The model checksum error and constants
+    pub(crate) fn checksum(&self) -> (TokenStream, TokenStream) {
+        let Some(checksum) = self.checksum else {
+            return (quote!(), quote!());
+        };
+        let control = &self.control;
+        let checksum_values = checksum.map(proc_macro2::Literal::u32_unsuffixed);
+        let checksum = checksum_string(checksum);
+        let assert_compatible = self.with_std(quote! {
+            /// Checks the model checksum against the `expected_checksum`
+            ///
+            /// The checksums are compared value by value, ignoring the white spaces
+            pub fn assert_compatible_with(
+                expected_checksum: &str,
+            ) -> Result<(), [<#control IncompatibleModel>]> {
+                if expected_checksum
+                    .split_whitespace()
+                    .eq(Self::MODEL_CHECKSUM.split_whitespace())
+                {
+                    Ok(())
+                } else {
+                    Err([<#control IncompatibleModel>] {
+                        expected: expected_checksum.to_string(),
+                        found: Self::MODEL_CHECKSUM,
+                    })
+                }
+            }
+        });
+        (
+            self.with_std(quote! {
+                /// Mismatch between the model checksum and an expected checksum
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct [<#control IncompatibleModel>] {
+                    /// The expected checksum
+                    pub expected: String,
+                    /// The model checksum
+                    pub found: &'static str,
+                }
+                impl core::fmt::Display for [<#control IncompatibleModel>] {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        write!(
+                            f,
+                            "model checksum {} doesn't match the expected checksum {}",
+                            self.found, self.expected
+                        )
+                    }
+                }
+                impl core::error::Error for [<#control IncompatibleModel>] {}
+            }),
+            quote! {
+                /// Model checksum
+                ///
+                /// The 4 checksum values set by the model, as space separated decimal numbers
+                pub const MODEL_CHECKSUM: &str = #checksum;
+                /// Model checksum
+                ///
+                /// The 4 checksum values set by the model
+                pub const CHECKSUM: [u32; 4] = [#(#checksum_values),*];
+                /// Returns `true` if the model checksum is `other`
+                pub fn checksum_matches(other: [u32; 4]) -> bool {
+                    Self::CHECKSUM == other
+                }
+                #assert_compatible
+            },
+        )
+    }
+}
@@ -1,35 +1,50 @@
-pub use simulink_binder::import;
+#![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(feature = "approx")]
+pub use approx;
+#[cfg(feature = "nalgebra")]
+pub use nalgebra;
+#[cfg(feature = "serde")]
+pub use serde;
+#[cfg(feature = "serde")]
+pub use serde_big_array;
+pub use simulink_binder::{feedback, import, import_bindings};
+#[cfg(feature = "std")]
+pub use simulink_binder_core::{
+    parse_model_header, parse_model_header_cached, ModelInfo, ParseError, Variable,
+};
+#[cfg(feature = "std")]
 use std::{
     env, fs,
     path::{Path, PathBuf},
 };
 
+#[cfg(feature = "std")]
 pub fn build() {
     let lib = env::var("CARGO_PKG_NAME").unwrap();
-    let sys = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("sys");
+    // the Simulink C model directory, `sys` unless set with `SIMULINK_BINDER_DIR`
+    println!("cargo:rerun-if-env-changed=SIMULINK_BINDER_DIR");
+    let dir = env::var("SIMULINK_BINDER_DIR").unwrap_or_else(|_| "sys".to_string());
+    let sys = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(dir);
 
     let mut cc_builder = cc::Build::new();
     let mut bindings = bindgen::builder();
 
     if let Ok(entries) = fs::read_dir(sys) {
-        for entry in entries {
-            if let Ok(entry) = entry {
-                let file_name = entry.path();
-                if let Some(extension) = file_name.extension() {
-                    match extension.to_str() {
-                        Some("c") => {
-                            cc_builder.file(file_name);
-                        }
-                        Some("h") => {
-                            bindings = bindings.header(
-                                file_name
-                                    .to_str()
-                                    .expect(&format!("{:?} conversion to str failed", file_name)),
-                            );
-                            println!("cargo:rerun-if-changed={:}", file_name.to_str().unwrap());
-                        }
-                        _ => (),
+        for entry in entries.flatten() {
+            let file_name = entry.path();
+            if let Some(extension) = file_name.extension() {
+                match extension.to_str() {
+                    Some("c") => {
+                        cc_builder.file(file_name);
+                    }
+                    Some("h") => {
+                        bindings =
+                            bindings.header(file_name.to_str().unwrap_or_else(|| {
+                                panic!("{:?} conversion to str failed", file_name)
+                            }));
+                        println!("cargo:rerun-if-changed={:}", file_name.to_str().unwrap());
                     }
+                    _ => (),
                 }
             }
         }
@@ -40,7 +55,9 @@ pub fn build() {
     println!("cargo:rustc-link-search=native=lib{}", lib);
     println!("cargo:rustc-link-lib={}", lib);
 
+    // the bindings use the `core` C types for the `no_std` wrappers
     let bindings = bindings
+        .use_core()
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         .generate()
         .expect("Unable to generate bindings");
@@ -50,3 +67,106 @@ pub fn build() {
         .write_to_file(out_path.join("bindings.rs"))
         .expect("Couldn't write bindings!");
 }
+
+/// Fixed size view of an array signal
+///
+/// The array outputs getters of the [import] macro return arrays that implement [AsVector],
+/// e.g. `fn gain(v: &impl AsVector<3>) -> f64`
+pub trait AsVector<const N: usize, T = f64> {
+    /// Returns the signal as an array
+    fn as_array(&self) -> &[T; N];
+}
+impl<T, const N: usize> AsVector<N, T> for [T; N] {
+    fn as_array(&self) -> &[T; N] {
+        self
+    }
+}
+
+/// Simulink model wrapper
+///
+/// The single-rate model wrappers of the [import] macro implement [SimulinkModel],
+/// e.g. `fn run<M: SimulinkModel>(model: &mut M, n: usize)`
+pub trait SimulinkModel {
+    /// Number of inputs elements, arrays element-wise
+    const INPUT_LEN: usize;
+    /// Number of outputs elements, arrays element-wise
+    const OUTPUT_LEN: usize;
+    /// Steps the model
+    fn step(&mut self);
+    /// Resets the model in place
+    fn reset(&mut self);
+}
+
+/// Simulink model wrapper error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimulinkError {
+    /// The slice given to an array input setter of the [import] macro doesn't match the array length
+    WrongLength {
+        /// The input name
+        field: &'static str,
+        /// The array length
+        expected: usize,
+        /// The slice length
+        got: usize,
+    },
+    /// The index given to an array input indexed setter, or the task given to the multi-rate
+    /// `step`, of the [import] macro is out of bounds
+    IndexOutOfBounds {
+        /// The input name, or `task_id`
+        field: &'static str,
+        /// The index
+        index: usize,
+        /// The array length, or the number of tasks
+        len: usize,
+    },
+    /// The slice given to the parameters conversion of the [import] macro doesn't match
+    /// the number of parameters elements
+    WrongParametersLength {
+        /// The number of parameters elements
+        expected: usize,
+        /// The slice length
+        got: usize,
+    },
+}
+impl core::fmt::Display for SimulinkError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SimulinkError::WrongLength {
+                field,
+                expected,
+                got,
+            } => write!(
+                f,
+                "expected {} values for the input `{}`, got {}",
+                expected, field, got
+            ),
+            SimulinkError::IndexOutOfBounds { field, index, len } => write!(
+                f,
+                "index {} is out of bounds of `{}` of length {}",
+                index, field, len
+            ),
+            SimulinkError::WrongParametersLength { expected, got } => {
+                write!(f, "expected {} parameters values, got {}", expected, got)
+            }
+        }
+    }
+}
+impl core::error::Error for SimulinkError {}
+
+/// Simulink model provenance
+///
+/// The model name and the fields of the model header comment, returned by the `metadata`
+/// function of the [import] macro wrappers, `None` for the fields missing in the header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelMetadata {
+    /// The model name
+    pub name: &'static str,
+    /// The `Model version` field
+    pub version: Option<&'static str>,
+    /// The `Simulink Coder version` field
+    pub coder_version: Option<&'static str>,
+    /// The `C/C++ source code generated on` field
+    pub codegen_date: Option<&'static str>,
+    /// The `Solver` field
+    pub solver: Option<&'static str>,
+}
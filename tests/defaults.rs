@@ -0,0 +1,55 @@
+// The hidden test of the macro comparing the structures `Default` with `core::mem::zeroed`,
+// run by `cargo test` as `edges_defaults_are_zeroed`
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/zerocrossing/zerocrossing.h`
+pub type real_T = f64;
+pub type int32_T = i32;
+pub type char_T = ::std::os::raw::c_char;
+pub type ZCSigState = u8;
+pub type RT_MODEL_zerocrossing_T = tag_RTM_zerocrossing_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_zerocrossing_T {
+    pub Counter_DSTATE: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct PrevZCX_zerocrossing_T {
+    pub Counter_Trig_ZCE: ZCSigState,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_zerocrossing_T {
+    pub trigger: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_zerocrossing_T {
+    pub edges: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_zerocrossing_T {
+    pub errorStatus: *const char_T,
+    pub prevZCSigState: *mut PrevZCX_zerocrossing_T,
+    pub dwork: *mut DW_zerocrossing_T,
+}
+
+// the model C source, a no-op
+#[no_mangle]
+unsafe extern "C" fn zerocrossing_initialize(
+    _zerocrossing_M: *mut RT_MODEL_zerocrossing_T,
+    _zerocrossing_U: *mut ExtU_zerocrossing_T,
+    _zerocrossing_Y: *mut ExtY_zerocrossing_T,
+) {
+}
+#[no_mangle]
+unsafe extern "C" fn zerocrossing_step(
+    _zerocrossing_M: *mut RT_MODEL_zerocrossing_T,
+    _zerocrossing_U: *mut ExtU_zerocrossing_T,
+    _zerocrossing_Y: *mut ExtY_zerocrossing_T,
+) {
+}
+
+simulink_rs::import! {Edges, "tests/fixtures/zerocrossing/zerocrossing.h", bindings = false}
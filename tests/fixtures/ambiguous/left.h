@@ -0,0 +1,35 @@
+/*
+ * File: left.h
+ *
+ * Code generated for Simulink model 'left'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.5
+ */
+
+#ifndef RTW_HEADER_left_h_
+#define RTW_HEADER_left_h_
+#include "rtwtypes.h"
+#include "left_types.h"
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+  real_T w[2];                         /* '<Root>/w' */
+} ExtY_left_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_left_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void left_initialize(RT_MODEL_left_T *const left_M,
+  ExtY_left_T *left_Y);
+extern void left_step(RT_MODEL_left_T *const left_M,
+  ExtY_left_T *left_Y);
+
+#endif                                 /* RTW_HEADER_left_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: left_types.h
+ *
+ * Code generated for Simulink model 'left'.
+ */
+
+#ifndef RTW_HEADER_left_types_h_
+#define RTW_HEADER_left_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_left_T RT_MODEL_left_T;
+
+#endif                                 /* RTW_HEADER_left_types_h_ */
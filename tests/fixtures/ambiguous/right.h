@@ -0,0 +1,35 @@
+/*
+ * File: right.h
+ *
+ * Code generated for Simulink model 'right'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.5
+ */
+
+#ifndef RTW_HEADER_right_h_
+#define RTW_HEADER_right_h_
+#include "rtwtypes.h"
+#include "right_types.h"
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+  real_T w[2];                         /* '<Root>/w' */
+} ExtY_right_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_right_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void right_initialize(RT_MODEL_right_T *const right_M,
+  ExtY_right_T *right_Y);
+extern void right_step(RT_MODEL_right_T *const right_M,
+  ExtY_right_T *right_Y);
+
+#endif                                 /* RTW_HEADER_right_h_ */
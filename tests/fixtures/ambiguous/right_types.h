@@ -0,0 +1,13 @@
+/*
+ * File: right_types.h
+ *
+ * Code generated for Simulink model 'right'.
+ */
+
+#ifndef RTW_HEADER_right_types_h_
+#define RTW_HEADER_right_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_right_T RT_MODEL_right_T;
+
+#endif                                 /* RTW_HEADER_right_types_h_ */
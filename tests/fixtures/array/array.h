@@ -0,0 +1,64 @@
+/*
+ * File: array.h
+ *
+ * Code generated for Simulink model 'array'.
+ *
+ * Model version                  : 2.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_array_h_
+#define RTW_HEADER_array_h_
+#include "rtwtypes.h"
+#include "array_types.h"
+#define NUM_Y                          2U
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T Delay_DSTATE[3];              /* '<Root>/Delay' */
+  real_T Scratch_DWORK[8];             /* '<Root>/Scratch' */
+} DW_array_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T A[3];                         /* '<Root>/A' */
+  real_T B[3][4];                      /* '<Root>/B' */
+} ExtU_array_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y[NUM_Y];                     /* '<Root>/y' */
+  uint16_T n;                          /* '<Root>/n' */
+} ExtY_array_T;
+
+/* Parameters (default storage) */
+struct P_array_T_ {
+  real_T Gain;                         /* Expression: 2
+                                        * Referenced by: '<Root>/Gain'
+                                        */
+  real_T K[2][3];                      /* Expression: [1 2 3; 4 5 6]
+                                        * Referenced by: '<Root>/K'
+                                        */
+  boolean_T Enable;                    /* Computed Parameter: Enable
+                                        * Referenced by: '<Root>/Enable'
+                                        */
+};
+
+/* Real-time Model Data Structure */
+struct tag_RTM_array_T {
+  P_array_T *defaultParam;
+  DW_array_T *dwork;
+};
+
+/* Block parameters (default storage) */
+extern P_array_T array_P;
+
+/* Model entry point functions */
+extern void array_initialize(RT_MODEL_array_T *const array_M, ExtU_array_T
+  *array_U, ExtY_array_T *array_Y);
+extern void array_step(RT_MODEL_array_T *const array_M, ExtU_array_T *array_U,
+  ExtY_array_T *array_Y);
+
+#endif                                 /* RTW_HEADER_array_h_ */
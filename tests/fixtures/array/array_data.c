@@ -0,0 +1,30 @@
+/*
+ * File: array_data.c
+ *
+ * Code generated for Simulink model 'array'.
+ *
+ * Model version                  : 2.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ */
+
+#include "array.h"
+
+/* Block parameters (default storage) */
+P_array_T array_P = {
+  /* Expression: 2
+   * Referenced by: '<Root>/Gain'
+   */
+  2.0,
+
+  /* Expression: [1 2 3; 4 5 6]
+   * Referenced by: '<Root>/K'
+   */
+  { { 1.0, 2.0, 3.0 }, { 4.0, 5.0, -6.0E+0 } },
+
+  /* Computed Parameter: Enable
+   * Referenced by: '<Root>/Enable'
+   */
+  1U
+};
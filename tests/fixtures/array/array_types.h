@@ -0,0 +1,16 @@
+/*
+ * File: array_types.h
+ *
+ * Code generated for Simulink model 'array'.
+ */
+
+#ifndef RTW_HEADER_array_types_h_
+#define RTW_HEADER_array_types_h_
+
+/* Parameters (default storage) */
+typedef struct P_array_T_ P_array_T;
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_array_T RT_MODEL_array_T;
+
+#endif                                 /* RTW_HEADER_array_types_h_ */
@@ -0,0 +1,46 @@
+/*
+ * File: bigarray.h
+ *
+ * Code generated for Simulink model 'bigarray'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.001
+ */
+
+#ifndef RTW_HEADER_bigarray_h_
+#define RTW_HEADER_bigarray_h_
+#include "rtwtypes.h"
+#include "bigarray_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T K_DSTATE[6][40];              /* '<Root>/K' */
+  real_T Delay_DSTATE[2];              /* '<Root>/Delay' */
+} DW_bigarray_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u[40];                        /* '<Root>/u' */
+} ExtU_bigarray_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y[6];                         /* '<Root>/y' */
+} ExtY_bigarray_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_bigarray_T {
+  const char_T * volatile errorStatus;
+  DW_bigarray_T *dwork;
+};
+
+/* Model entry point functions */
+extern void bigarray_initialize(RT_MODEL_bigarray_T *const bigarray_M,
+  ExtU_bigarray_T *bigarray_U, ExtY_bigarray_T *bigarray_Y);
+extern void bigarray_step(RT_MODEL_bigarray_T *const bigarray_M,
+  ExtU_bigarray_T *bigarray_U, ExtY_bigarray_T *bigarray_Y);
+
+#endif                                 /* RTW_HEADER_bigarray_h_ */
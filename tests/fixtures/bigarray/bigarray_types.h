@@ -0,0 +1,13 @@
+/*
+ * File: bigarray_types.h
+ *
+ * Code generated for Simulink model 'bigarray'.
+ */
+
+#ifndef RTW_HEADER_bigarray_types_h_
+#define RTW_HEADER_bigarray_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_bigarray_T RT_MODEL_bigarray_T;
+
+#endif                                 /* RTW_HEADER_bigarray_types_h_ */
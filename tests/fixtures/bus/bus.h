@@ -0,0 +1,50 @@
+/*
+ * File: bus.h
+ *
+ * Code generated for Simulink model 'bus'.
+ *
+ * Model version                  : 1.4
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.01
+ */
+
+#ifndef RTW_HEADER_bus_h_
+#define RTW_HEADER_bus_h_
+#include "rtwtypes.h"
+#include "bus_types.h"
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+  struct {
+    real_T signal;                     /* '<Root>/bus/signal' */
+    int32_T count;                     /* '<Root>/bus/count' */
+    struct {
+      real_T v[2];                     /* '<Root>/bus/sub/v' */
+    } sub;
+  } bus;
+} ExtU_bus_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  struct Pose_tag {
+    real_T x;                          /* '<Root>/pose/x' */
+    real_T y;                          /* '<Root>/pose/y' */
+  } pose;
+  real_T sum;                          /* '<Root>/sum' */
+} ExtY_bus_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_bus_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void bus_initialize(RT_MODEL_bus_T *const bus_M, ExtU_bus_T *bus_U,
+  ExtY_bus_T *bus_Y);
+extern void bus_step(RT_MODEL_bus_T *const bus_M, ExtU_bus_T *bus_U,
+  ExtY_bus_T *bus_Y);
+
+#endif                                 /* RTW_HEADER_bus_h_ */
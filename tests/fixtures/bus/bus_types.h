@@ -0,0 +1,13 @@
+/*
+ * File: bus_types.h
+ *
+ * Code generated for Simulink model 'bus'.
+ */
+
+#ifndef RTW_HEADER_bus_types_h_
+#define RTW_HEADER_bus_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_bus_T RT_MODEL_bus_T;
+
+#endif                                 /* RTW_HEADER_bus_types_h_ */
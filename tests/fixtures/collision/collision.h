@@ -0,0 +1,40 @@
+/*
+ * File: collision.h
+ *
+ * Code generated for Simulink model 'collision'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ * C/C++ source code generated on : Wed Mar  8 16:40:02 2023
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_collision_h_
+#define RTW_HEADER_collision_h_
+#include "rtwtypes.h"
+#include "collision_types.h"
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+} ExtU_collision_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T fooBar;                       /* '<Root>/fooBar' */
+  real_T foo_bar;                      /* '<Root>/foo_bar' */
+} ExtY_collision_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_collision_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void collision_initialize(RT_MODEL_collision_T *const collision_M,
+  ExtU_collision_T *collision_U, ExtY_collision_T *collision_Y);
+extern void collision_step(RT_MODEL_collision_T *const collision_M,
+  ExtU_collision_T *collision_U, ExtY_collision_T *collision_Y);
+
+#endif                                 /* RTW_HEADER_collision_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: collision_types.h
+ *
+ * Code generated for Simulink model 'collision'.
+ */
+
+#ifndef RTW_HEADER_collision_types_h_
+#define RTW_HEADER_collision_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_collision_T RT_MODEL_collision_T;
+
+#endif                                 /* RTW_HEADER_collision_types_h_ */
@@ -0,0 +1,42 @@
+/*
+ * File: counts.h
+ *
+ * Code generated for Simulink model 'counts'.
+ *
+ * Model version                  : 1.4
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.001
+ */
+
+#ifndef RTW_HEADER_counts_h_
+#define RTW_HEADER_counts_h_
+#include "rtwtypes.h"
+#include "counts_types.h"
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u[3];                         /* '<Root>/u' */
+  int32_T offsets[2];                  /* '<Root>/offsets' */
+} ExtU_counts_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  uint16_T counts[4];                  /* '<Root>/counts' */
+  int32_T total[2][2];                 /* '<Root>/total' */
+  real_T y;                            /* '<Root>/y' */
+} ExtY_counts_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_counts_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void counts_initialize(RT_MODEL_counts_T *const counts_M, ExtU_counts_T
+  *counts_U, ExtY_counts_T *counts_Y);
+extern void counts_step(RT_MODEL_counts_T *const counts_M, ExtU_counts_T
+  *counts_U, ExtY_counts_T *counts_Y);
+
+#endif                                 /* RTW_HEADER_counts_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: counts_types.h
+ *
+ * Code generated for Simulink model 'counts'.
+ */
+
+#ifndef RTW_HEADER_counts_types_h_
+#define RTW_HEADER_counts_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_counts_T RT_MODEL_counts_T;
+
+#endif                                 /* RTW_HEADER_counts_types_h_ */
@@ -0,0 +1,41 @@
+/*
+ * File: crlf.h
+ *
+ * Code generated for Simulink model 'crlf'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.1
+ */
+
+#ifndef RTW_HEADER_crlf_h_
+#define RTW_HEADER_crlf_h_
+#include "rtwtypes.h"
+#include "crlf_types.h"
+
+/* External inputs (root inport signals with default storage) */
+	typedef	struct {
+		real_T	u;                   /* '<Root>/{u}' */
+		real_T v[2];                 /* '<Root>/v' */
+	} ExtU_crlf_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+  typedef struct
+  {
+    real_T y;                          /* '<Root>/y' */
+  } ExtY_crlf_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_crlf_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void crlf_initialize(RT_MODEL_crlf_T *const crlf_M, ExtU_crlf_T *crlf_U,
+  ExtY_crlf_T *crlf_Y);
+extern void crlf_step(RT_MODEL_crlf_T *const crlf_M, ExtU_crlf_T *crlf_U,
+  ExtY_crlf_T *crlf_Y);
+
+#endif                                 /* RTW_HEADER_crlf_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: crlf_types.h
+ *
+ * Code generated for Simulink model 'crlf'.
+ */
+
+#ifndef RTW_HEADER_crlf_types_h_
+#define RTW_HEADER_crlf_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_crlf_T RT_MODEL_crlf_T;
+
+#endif                                 /* RTW_HEADER_crlf_types_h_ */
@@ -0,0 +1,35 @@
+/*
+ * File: discovery.h
+ *
+ * Code generated for Simulink model 'discovery'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.5
+ */
+
+#ifndef RTW_HEADER_discovery_h_
+#define RTW_HEADER_discovery_h_
+#include "rtwtypes.h"
+#include "discovery_types.h"
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+  real_T w[2];                         /* '<Root>/w' */
+} ExtY_discovery_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_discovery_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void discovery_initialize(RT_MODEL_discovery_T *const discovery_M,
+  ExtY_discovery_T *discovery_Y);
+extern void discovery_step(RT_MODEL_discovery_T *const discovery_M,
+  ExtY_discovery_T *discovery_Y);
+
+#endif                                 /* RTW_HEADER_discovery_h_ */
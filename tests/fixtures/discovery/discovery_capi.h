@@ -0,0 +1,16 @@
+/*
+ * File: discovery_capi.h
+ *
+ * Code generated for Simulink model 'discovery'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ */
+
+#ifndef RTW_HEADER_discovery_capi_h_
+#define RTW_HEADER_discovery_capi_h_
+#include "discovery.h"
+
+extern void discovery_InitializeDataMapInfo(RT_MODEL_discovery_T *const discovery_M);
+
+#endif                                 /* RTW_HEADER_discovery_capi_h_ */
@@ -0,0 +1,15 @@
+/*
+ * File: discovery_dt.h
+ *
+ * Code generated for Simulink model 'discovery'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ */
+
+#include "ext_types.h"
+
+/* data type size table */
+static uint_T rtDataTypeSizes[] = {
+  sizeof(real_T)
+};
@@ -0,0 +1,13 @@
+/*
+ * File: discovery_types.h
+ *
+ * Code generated for Simulink model 'discovery'.
+ */
+
+#ifndef RTW_HEADER_discovery_types_h_
+#define RTW_HEADER_discovery_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_discovery_T RT_MODEL_discovery_T;
+
+#endif                                 /* RTW_HEADER_discovery_types_h_ */
@@ -0,0 +1,10 @@
+/*
+ * File: ert_main.h
+ */
+
+#ifndef RTW_HEADER_ert_main_h_
+#define RTW_HEADER_ert_main_h_
+
+extern void rt_OneStep(void);
+
+#endif                                 /* RTW_HEADER_ert_main_h_ */
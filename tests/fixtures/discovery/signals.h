@@ -0,0 +1,13 @@
+/*
+ * File: signals.h
+ *
+ * Hand-written signal names of the discovery model
+ */
+
+#ifndef SIGNALS_H_
+#define SIGNALS_H_
+
+#define SIGNAL_Y                       0
+#define SIGNAL_W                       1
+
+#endif                                 /* SIGNALS_H_ */
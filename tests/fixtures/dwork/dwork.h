@@ -0,0 +1,45 @@
+/*
+ * File: dwork.h
+ *
+ * Code generated for Simulink model 'dwork'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_dwork_h_
+#define RTW_HEADER_dwork_h_
+#include "rtwtypes.h"
+#include "dwork_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T Delay_DSTATE;                 /* '<Root>/Delay' */
+  real_T *Buffer_PWORK;                /* '<Root>/Buffer' */
+} DW_dwork_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+} ExtU_dwork_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+} ExtY_dwork_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_dwork_T {
+  const char_T * volatile errorStatus;
+  DW_dwork_T *dwork;
+};
+
+/* Model entry point functions */
+extern void dwork_initialize(RT_MODEL_dwork_T *const dwork_M, ExtU_dwork_T
+  *dwork_U, ExtY_dwork_T *dwork_Y);
+extern void dwork_step(RT_MODEL_dwork_T *const dwork_M, ExtU_dwork_T *dwork_U,
+  ExtY_dwork_T *dwork_Y);
+
+#endif                                 /* RTW_HEADER_dwork_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: dwork_types.h
+ *
+ * Code generated for Simulink model 'dwork'.
+ */
+
+#ifndef RTW_HEADER_dwork_types_h_
+#define RTW_HEADER_dwork_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_dwork_T RT_MODEL_dwork_T;
+
+#endif                                 /* RTW_HEADER_dwork_types_h_ */
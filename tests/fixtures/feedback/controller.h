@@ -0,0 +1,40 @@
+/*
+ * File: controller.h
+ *
+ * Code generated for Simulink model 'controller'.
+ *
+ * Model version                  : 1.0
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.01
+ */
+
+#ifndef RTW_HEADER_controller_h_
+#define RTW_HEADER_controller_h_
+#include "rtwtypes.h"
+#include "controller_types.h"
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+} ExtU_controller_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+  Mode_T mode;                         /* '<Root>/mode' */
+} ExtY_controller_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_controller_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void controller_initialize(RT_MODEL_controller_T *const controller_M,
+  ExtU_controller_T *controller_U, ExtY_controller_T *controller_Y);
+extern void controller_step(RT_MODEL_controller_T *const controller_M,
+  ExtU_controller_T *controller_U, ExtY_controller_T *controller_Y);
+
+#endif                                 /* RTW_HEADER_controller_h_ */
@@ -0,0 +1,23 @@
+/*
+ * File: controller_types.h
+ *
+ * Code generated for Simulink model 'controller'.
+ */
+
+#ifndef RTW_HEADER_controller_types_h_
+#define RTW_HEADER_controller_types_h_
+
+#ifndef DEFINED_TYPEDEF_FOR_Mode_T_
+#define DEFINED_TYPEDEF_FOR_Mode_T_
+
+typedef enum {
+  Hold = 0,                            /* Default value */
+  Track
+} Mode_T;
+
+#endif
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_controller_T RT_MODEL_controller_T;
+
+#endif                                 /* RTW_HEADER_controller_types_h_ */
@@ -0,0 +1,45 @@
+/*
+ * File: plant.h
+ *
+ * Code generated for Simulink model 'plant'.
+ *
+ * Model version                  : 1.0
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.01
+ */
+
+#ifndef RTW_HEADER_plant_h_
+#define RTW_HEADER_plant_h_
+#include "rtwtypes.h"
+#include "plant_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T Integrator_DSTATE;            /* '<Root>/Integrator' */
+} DW_plant_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+} ExtU_plant_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+} ExtY_plant_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_plant_T {
+  const char_T * volatile errorStatus;
+  DW_plant_T *dwork;
+};
+
+/* Model entry point functions */
+extern void plant_initialize(RT_MODEL_plant_T *const plant_M, ExtU_plant_T
+  *plant_U, ExtY_plant_T *plant_Y);
+extern void plant_step(RT_MODEL_plant_T *const plant_M, ExtU_plant_T *plant_U,
+  ExtY_plant_T *plant_Y);
+
+#endif                                 /* RTW_HEADER_plant_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: plant_types.h
+ *
+ * Code generated for Simulink model 'plant'.
+ */
+
+#ifndef RTW_HEADER_plant_types_h_
+#define RTW_HEADER_plant_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_plant_T RT_MODEL_plant_T;
+
+#endif                                 /* RTW_HEADER_plant_types_h_ */
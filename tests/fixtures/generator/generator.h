@@ -0,0 +1,35 @@
+/*
+ * File: generator.h
+ *
+ * Code generated for Simulink model 'generator'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.5
+ */
+
+#ifndef RTW_HEADER_generator_h_
+#define RTW_HEADER_generator_h_
+#include "rtwtypes.h"
+#include "generator_types.h"
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+  real_T w[2];                         /* '<Root>/w' */
+} ExtY_generator_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_generator_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void generator_initialize(RT_MODEL_generator_T *const generator_M,
+  ExtY_generator_T *generator_Y);
+extern void generator_step(RT_MODEL_generator_T *const generator_M,
+  ExtY_generator_T *generator_Y);
+
+#endif                                 /* RTW_HEADER_generator_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: generator_types.h
+ *
+ * Code generated for Simulink model 'generator'.
+ */
+
+#ifndef RTW_HEADER_generator_types_h_
+#define RTW_HEADER_generator_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_generator_T RT_MODEL_generator_T;
+
+#endif                                 /* RTW_HEADER_generator_types_h_ */
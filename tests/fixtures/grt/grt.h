@@ -0,0 +1,54 @@
+/*
+ * File: grt.h
+ *
+ * Code generated for Simulink model 'grt'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: grt.tlc
+ */
+
+#ifndef RTW_HEADER_grt_h_
+#define RTW_HEADER_grt_h_
+#include "rtwtypes.h"
+#include "grt_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T Integrator_DSTATE;            /* '<Root>/Integrator' */
+} DW_grt_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+} ExtU_grt_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+} ExtY_grt_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_grt_T {
+  const char_T *errorStatus;
+};
+
+/* Block states (default storage) */
+extern DW_grt_T grt_DW;
+
+/* External inputs (root inport signals with default storage) */
+extern ExtU_grt_T grt_U;
+
+/* External outputs (root outports fed by signals with default storage) */
+extern ExtY_grt_T grt_Y;
+
+/* Model entry point functions */
+extern void grt_initialize(void);
+extern void grt_step(void);
+extern void grt_terminate(void);
+
+/* Real-time Model object */
+extern RT_MODEL_grt_T *const grt_M;
+
+#endif                                 /* RTW_HEADER_grt_h_ */
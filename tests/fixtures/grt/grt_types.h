@@ -0,0 +1,13 @@
+/*
+ * File: grt_types.h
+ *
+ * Code generated for Simulink model 'grt'.
+ */
+
+#ifndef RTW_HEADER_grt_types_h_
+#define RTW_HEADER_grt_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_grt_T RT_MODEL_grt_T;
+
+#endif                                 /* RTW_HEADER_grt_types_h_ */
@@ -0,0 +1,46 @@
+/*
+ * File: keywords.h
+ *
+ * Code generated for Simulink model 'keywords'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_keywords_h_
+#define RTW_HEADER_keywords_h_
+#include "rtwtypes.h"
+#include "keywords_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T match;                        /* '<Root>/match' */
+} DW_keywords_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T type;                         /* '<Root>/type' */
+  real_T 2nd;                          /* '<Root>/2nd' */
+} ExtU_keywords_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T move;                         /* '<Root>/move' */
+  real_T 3rd[2];                       /* '<Root>/3rd' */
+} ExtY_keywords_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_keywords_T {
+  const char_T * volatile errorStatus;
+  DW_keywords_T *dwork;
+};
+
+/* Model entry point functions */
+extern void keywords_initialize(RT_MODEL_keywords_T *const keywords_M,
+  ExtU_keywords_T *keywords_U, ExtY_keywords_T *keywords_Y);
+extern void keywords_step(RT_MODEL_keywords_T *const keywords_M,
+  ExtU_keywords_T *keywords_U, ExtY_keywords_T *keywords_Y);
+
+#endif                                 /* RTW_HEADER_keywords_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: keywords_types.h
+ *
+ * Code generated for Simulink model 'keywords'.
+ */
+
+#ifndef RTW_HEADER_keywords_types_h_
+#define RTW_HEADER_keywords_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_keywords_T RT_MODEL_keywords_T;
+
+#endif                                 /* RTW_HEADER_keywords_types_h_ */
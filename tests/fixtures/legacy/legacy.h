@@ -0,0 +1,59 @@
+/*
+ * File: legacy.h
+ *
+ * Code generated for Simulink model 'legacy'.
+ *
+ * Model version                  : 1.12
+ * Simulink Coder version         : 8.7 (R2014b) 08-Sep-2014
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_legacy_h_
+#define RTW_HEADER_legacy_h_
+#include "rtwtypes.h"
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_legacy_T RT_MODEL_legacy_T;
+
+/* Block signals and states (auto storage) for system '<Root>' */
+typedef struct {
+  real_T UnitDelay_DSTATE;             /* '<Root>/Unit Delay' */
+} DW_legacy_T;
+
+/* External inputs (root inport signals with auto storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+} ExtU_legacy_T;
+
+/* External outputs (root outports fed by signals with auto storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+} ExtY_legacy_T;
+
+/* Parameters (auto storage) */
+struct P_legacy_T_ {
+  real_T Gain;                         /* Expression: 2
+                                        * Referenced by: '<Root>/Gain'
+                                        */
+};
+
+/* Real-time Model Data Structure */
+struct tag_RTM_legacy_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Block signals and states (auto storage) */
+extern DW_legacy_T legacy_DW;
+
+/* External inputs (root inport signals with auto storage) */
+extern ExtU_legacy_T legacy_U;
+
+/* External outputs (root outports fed by signals with auto storage) */
+extern ExtY_legacy_T legacy_Y;
+
+/* Model entry point functions */
+extern void legacy_initialize(void);
+extern void legacy_step(void);
+
+#endif                                 /* RTW_HEADER_legacy_h_ */
@@ -0,0 +1,14 @@
+/*
+ * Hand-trimmed Simulink header without the `File:` line nor the structure tags
+ */
+
+#ifndef RTW_HEADER_malformed_h_
+#define RTW_HEADER_malformed_h_
+#include "rtwtypes.h"
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+} inputs_T;
+
+#endif                                 /* RTW_HEADER_malformed_h_ */
@@ -0,0 +1,54 @@
+/*
+ * File: relabeled.h
+ *
+ * Code generated for Simulink model 'relabeled'.
+ *
+ * Model version                  : 3.0
+ * Simulink Coder version         : 23.2 (R2023b) 01-Aug-2023
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_relabeled_h_
+#define RTW_HEADER_relabeled_h_
+#include "rtwtypes.h"
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_relabeled_T RT_MODEL_relabeled_T;
+
+/* Block signals (default storage) */
+typedef struct {
+  real_T Sum;                          /* '<Root>/Sum' */
+} B_relabeled_T;
+
+/* DWork */
+typedef struct {
+  real_T Integrator_DSTATE;            /* '<Root>/Integrator' */
+  int8_T Integrator_PrevResetState;    /* '<Root>/Integrator' */
+} DW_relabeled_T;
+
+/* Root inports */
+typedef struct
+{
+  real_T u;                            /* '<Root>/u' */
+  real_T reset;                        /* '<Root>/reset' */
+} ExtU_relabeled_T;
+
+typedef struct {
+  real_T y[2];                         /* '<Root>/y' */
+} ExtY_relabeled_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_relabeled_T {
+  const char_T * volatile errorStatus;
+  B_relabeled_T *blockIO;
+  DW_relabeled_T *dwork;
+};
+
+/* Model entry point functions */
+extern void relabeled_initialize(RT_MODEL_relabeled_T *const relabeled_M,
+  ExtU_relabeled_T *relabeled_U, ExtY_relabeled_T *relabeled_Y);
+extern void relabeled_step(RT_MODEL_relabeled_T *const relabeled_M,
+  ExtU_relabeled_T *relabeled_U, ExtY_relabeled_T *relabeled_Y);
+
+#endif                                 /* RTW_HEADER_relabeled_h_ */
@@ -0,0 +1,44 @@
+/*
+ * File: reusable.h
+ *
+ * Code generated for Simulink model 'reusable'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.001
+ */
+
+#ifndef RTW_HEADER_reusable_h_
+#define RTW_HEADER_reusable_h_
+#include "rtwtypes.h"
+#include "reusable_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T UnitDelay_DSTATE;             /* '<Root>/Unit Delay' */
+} DW_reusable_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+} ExtU_reusable_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+} ExtY_reusable_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_reusable_T {
+  ExtU_reusable_T *inputs;
+  ExtY_reusable_T *outputs;
+  DW_reusable_T *states;
+};
+
+/* Model entry point functions */
+extern void reusable_initialize(RT_MODEL_reusable_T *const reusable_M);
+extern void reusable_step(RT_MODEL_reusable_T *const reusable_M);
+
+#endif                                 /* RTW_HEADER_reusable_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: reusable_types.h
+ *
+ * Code generated for Simulink model 'reusable'.
+ */
+
+#ifndef RTW_HEADER_reusable_types_h_
+#define RTW_HEADER_reusable_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_reusable_T RT_MODEL_reusable_T;
+
+#endif                                 /* RTW_HEADER_reusable_types_h_ */
@@ -0,0 +1,54 @@
+/*
+ * File: scalar.h
+ *
+ * Code generated for Simulink model 'scalar'.
+ *
+ * Model version                  : 1.3
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ * C/C++ source code generated on : Mon Mar  6 14:22:31 2023
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.01
+ * Solver                         : FixedStepDiscrete
+ */
+
+#ifndef RTW_HEADER_scalar_h_
+#define RTW_HEADER_scalar_h_
+#include "rtwtypes.h"
+#include "scalar_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T Integrator_DSTATE;            /* '<Root>/Integrator' */
+} DW_scalar_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+  real32_T g;                          /* '<Root>/g' */
+  boolean_T enable;                    /* '<Root>/enable' */
+} ExtU_scalar_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+  int32_T count;                       /* '<Root>/count' */
+} ExtY_scalar_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_scalar_T {
+  const char_T * volatile errorStatus;
+  DW_scalar_T *work;
+};
+
+/* Model checksum, checked against the parameters file */
+static const uint32_T scalar_checksums[4] = { 0x1A2B3C4DU, 0x0U, 0xDEADBEEFU,
+  305419896U };
+
+/* Model entry point functions */
+extern void scalar_initialize(RT_MODEL_scalar_T *const scalar_M, ExtU_scalar_T
+  *scalar_U, ExtY_scalar_T *scalar_Y);
+extern void scalar_step(RT_MODEL_scalar_T *const scalar_M, ExtU_scalar_T
+  *scalar_U, ExtY_scalar_T *scalar_Y);
+
+#endif                                 /* RTW_HEADER_scalar_h_ */
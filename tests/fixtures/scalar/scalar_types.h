@@ -0,0 +1,13 @@
+/*
+ * File: scalar_types.h
+ *
+ * Code generated for Simulink model 'scalar'.
+ */
+
+#ifndef RTW_HEADER_scalar_types_h_
+#define RTW_HEADER_scalar_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_scalar_T RT_MODEL_scalar_T;
+
+#endif                                 /* RTW_HEADER_scalar_types_h_ */
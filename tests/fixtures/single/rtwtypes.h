@@ -0,0 +1,15 @@
+/*
+ * File: rtwtypes.h
+ *
+ * Code generated for Simulink model 'single'.
+ */
+
+#ifndef RTWTYPES_H
+#define RTWTYPES_H
+
+/* Single precision target, real_T is float */
+#define real_T                         float
+typedef unsigned char boolean_T;
+typedef char char_T;
+
+#endif                                 /* RTWTYPES_H */
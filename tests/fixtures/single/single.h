@@ -0,0 +1,40 @@
+/*
+ * File: single.h
+ *
+ * Code generated for Simulink model 'single'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.001
+ */
+
+#ifndef RTW_HEADER_single_h_
+#define RTW_HEADER_single_h_
+#include "rtwtypes.h"
+#include "single_types.h"
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u[2];                         /* '<Root>/u' */
+  boolean_T enable;                    /* '<Root>/enable' */
+} ExtU_single_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+} ExtY_single_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_single_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void single_initialize(RT_MODEL_single_T *const single_M, ExtU_single_T
+  *single_U, ExtY_single_T *single_Y);
+extern void single_step(RT_MODEL_single_T *const single_M, ExtU_single_T
+  *single_U, ExtY_single_T *single_Y);
+
+#endif                                 /* RTW_HEADER_single_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: single_types.h
+ *
+ * Code generated for Simulink model 'single'.
+ */
+
+#ifndef RTW_HEADER_single_types_h_
+#define RTW_HEADER_single_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_single_T RT_MODEL_single_T;
+
+#endif                                 /* RTW_HEADER_single_types_h_ */
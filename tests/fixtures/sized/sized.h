@@ -0,0 +1,49 @@
+/*
+ * File: sized.h
+ *
+ * Code generated for Simulink model 'sized'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.001
+ */
+
+#ifndef RTW_HEADER_sized_h_
+#define RTW_HEADER_sized_h_
+#include "rtwtypes.h"
+#include "sized_types.h"
+
+#define N                              3
+#define NSTATES                        2U
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  real_T Delay_DSTATE[2*NSTATES];      /* '<Root>/Delay' */
+} DW_sized_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T buf[2*N+1];                   /* '<Root>/buf' */
+  int32_T window[N][(N - 1) * 2];      /* '<Root>/window' */
+} ExtU_sized_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y[N + 1];                     /* '<Root>/y' */
+} ExtY_sized_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_sized_T {
+  const char_T * volatile errorStatus;
+  DW_sized_T *dwork;
+};
+
+/* Model entry point functions */
+extern void sized_initialize(RT_MODEL_sized_T *const sized_M, ExtU_sized_T
+  *sized_U, ExtY_sized_T *sized_Y);
+extern void sized_step(RT_MODEL_sized_T *const sized_M, ExtU_sized_T *sized_U,
+  ExtY_sized_T *sized_Y);
+
+#endif                                 /* RTW_HEADER_sized_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: sized_types.h
+ *
+ * Code generated for Simulink model 'sized'.
+ */
+
+#ifndef RTW_HEADER_sized_types_h_
+#define RTW_HEADER_sized_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_sized_T RT_MODEL_sized_T;
+
+#endif                                 /* RTW_HEADER_sized_types_h_ */
@@ -0,0 +1,25 @@
+/* Vendored header stripped of the Simulink header comment */
+
+#ifndef RTW_HEADER_stripped_h_
+#define RTW_HEADER_stripped_h_
+#include "rtwtypes.h"
+#include "stripped_types.h"
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+  real_T w[2];                         /* '<Root>/w' */
+} ExtY_stripped_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_stripped_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void stripped_initialize(RT_MODEL_stripped_T *const stripped_M,
+  ExtY_stripped_T *stripped_Y);
+extern void stripped_step(RT_MODEL_stripped_T *const stripped_M,
+  ExtY_stripped_T *stripped_Y);
+
+#endif                                 /* RTW_HEADER_stripped_h_ */
@@ -0,0 +1,11 @@
+/*
+ * Code generated for Simulink model 'stripped'.
+ */
+
+#ifndef RTW_HEADER_stripped_types_h_
+#define RTW_HEADER_stripped_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_stripped_T RT_MODEL_stripped_T;
+
+#endif                                 /* RTW_HEADER_stripped_types_h_ */
@@ -0,0 +1,49 @@
+/*
+ * File: tworate.h
+ *
+ * Code generated for Simulink model 'tworate'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ * C/C++ source code generated on : Tue Mar  7 09:12:45 2023
+ *
+ * Target selection: ert.tlc
+ * Solver                         : FixedStepDiscrete
+ */
+
+#ifndef RTW_HEADER_tworate_h_
+#define RTW_HEADER_tworate_h_
+#include "rtwtypes.h"
+#include "tworate_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  int32_T Slow_DSTATE;                 /* '<Root>/Slow' */
+} DW_tworate_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+} ExtU_tworate_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+  int32_T fast;                        /* '<Root>/fast' */
+  int32_T slow;                        /* '<Root>/slow' */
+} ExtY_tworate_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_tworate_T {
+  const char_T * volatile errorStatus;
+  DW_tworate_T *dwork;
+};
+
+/* Model entry point functions */
+extern void tworate_initialize(RT_MODEL_tworate_T *const tworate_M,
+  ExtU_tworate_T *tworate_U, ExtY_tworate_T *tworate_Y);
+extern void tworate_step0(RT_MODEL_tworate_T *const tworate_M, ExtU_tworate_T
+  *tworate_U, ExtY_tworate_T *tworate_Y); /* Sample time: [0.01s, 0.0s] */
+extern void tworate_step1(RT_MODEL_tworate_T *const tworate_M); /* Sample time: [0.1s, 0.05s] */
+
+#endif                                 /* RTW_HEADER_tworate_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: tworate_types.h
+ *
+ * Code generated for Simulink model 'tworate'.
+ */
+
+#ifndef RTW_HEADER_tworate_types_h_
+#define RTW_HEADER_tworate_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_tworate_T RT_MODEL_tworate_T;
+
+#endif                                 /* RTW_HEADER_tworate_types_h_ */
@@ -0,0 +1,18 @@
+/*
+ * File: unevaluated.h
+ *
+ * Code generated for Simulink model 'unevaluated'.
+ */
+
+#ifndef RTW_HEADER_unevaluated_h_
+#define RTW_HEADER_unevaluated_h_
+#include "rtwtypes.h"
+
+#define N                              4
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T w[N/2];                       /* '<Root>/w' */
+} ExtY_unevaluated_T;
+
+#endif                                 /* RTW_HEADER_unevaluated_h_ */
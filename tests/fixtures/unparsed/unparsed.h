@@ -0,0 +1,39 @@
+/*
+ * File: unparsed.h
+ *
+ * Code generated for Simulink model 'unparsed'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_unparsed_h_
+#define RTW_HEADER_unparsed_h_
+#include "rtwtypes.h"
+#include "unparsed_types.h"
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+  double gain;                         /* '<Root>/gain' */
+} ExtU_unparsed_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+} ExtY_unparsed_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_unparsed_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void unparsed_initialize(RT_MODEL_unparsed_T *const unparsed_M,
+  ExtU_unparsed_T *unparsed_U, ExtY_unparsed_T *unparsed_Y);
+extern void unparsed_step(RT_MODEL_unparsed_T *const unparsed_M,
+  ExtU_unparsed_T *unparsed_U, ExtY_unparsed_T *unparsed_Y);
+
+#endif                                 /* RTW_HEADER_unparsed_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: unparsed_types.h
+ *
+ * Code generated for Simulink model 'unparsed'.
+ */
+
+#ifndef RTW_HEADER_unparsed_types_h_
+#define RTW_HEADER_unparsed_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_unparsed_T RT_MODEL_unparsed_T;
+
+#endif                                 /* RTW_HEADER_unparsed_types_h_ */
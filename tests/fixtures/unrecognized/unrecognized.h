@@ -0,0 +1,41 @@
+/*
+ * File: unrecognized.h
+ *
+ * Code generated for Simulink model 'unrecognized'.
+ *
+ * Model version                  : 1.1
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_unrecognized_h_
+#define RTW_HEADER_unrecognized_h_
+#include "rtwtypes.h"
+#include "sensor_types.h"
+#include "unrecognized_types.h"
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T u;                            /* '<Root>/u' */
+  gain_T gain;                         /* '<Root>/gain' */
+} ExtU_unrecognized_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y;                            /* '<Root>/y' */
+  tick_T count;                        /* '<Root>/count' */
+} ExtY_unrecognized_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_unrecognized_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void unrecognized_initialize(RT_MODEL_unrecognized_T *const unrecognized_M,
+  ExtU_unrecognized_T *unrecognized_U, ExtY_unrecognized_T *unrecognized_Y);
+extern void unrecognized_step(RT_MODEL_unrecognized_T *const unrecognized_M,
+  ExtU_unrecognized_T *unrecognized_U, ExtY_unrecognized_T *unrecognized_Y);
+
+#endif                                 /* RTW_HEADER_unrecognized_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: unrecognized_types.h
+ *
+ * Code generated for Simulink model 'unrecognized'.
+ */
+
+#ifndef RTW_HEADER_unrecognized_types_h_
+#define RTW_HEADER_unrecognized_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_unrecognized_T RT_MODEL_unrecognized_T;
+
+#endif                                 /* RTW_HEADER_unrecognized_types_h_ */
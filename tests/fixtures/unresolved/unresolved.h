@@ -0,0 +1,16 @@
+/*
+ * File: unresolved.h
+ *
+ * Code generated for Simulink model 'unresolved'.
+ */
+
+#ifndef RTW_HEADER_unresolved_h_
+#define RTW_HEADER_unresolved_h_
+#include "rtwtypes.h"
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T w[NUM_W];                     /* '<Root>/w' */
+} ExtY_unresolved_T;
+
+#endif                                 /* RTW_HEADER_unresolved_h_ */
@@ -0,0 +1,46 @@
+/*
+ * File: volatile.h
+ *
+ * Code generated for Simulink model 'volatile'.
+ *
+ * Model version                  : 1.4
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ */
+
+#ifndef RTW_HEADER_volatile_h_
+#define RTW_HEADER_volatile_h_
+#include "rtwtypes.h"
+#include "volatile_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  volatile real_T Delay_DSTATE;        /* '<Root>/Delay' */
+} DW_volatile_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  volatile real_T u;                   /* '<Root>/u' */
+  real_T volatile v[2];                /* '<Root>/v' */
+  const volatile reg16_T status;       /* '<Root>/status' */
+} ExtU_volatile_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  volatile real_T y;                   /* '<Root>/y' */
+} ExtY_volatile_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_volatile_T {
+  const char_T * volatile errorStatus;
+  DW_volatile_T *dwork;
+};
+
+/* Model entry point functions */
+extern void volatile_initialize(RT_MODEL_volatile_T *const volatile_M,
+  ExtU_volatile_T *volatile_U, ExtY_volatile_T *volatile_Y);
+extern void volatile_step(RT_MODEL_volatile_T *const volatile_M,
+  ExtU_volatile_T *volatile_U, ExtY_volatile_T *volatile_Y);
+
+#endif                                 /* RTW_HEADER_volatile_h_ */
@@ -0,0 +1,16 @@
+/*
+ * File: volatile_types.h
+ *
+ * Code generated for Simulink model 'volatile'.
+ */
+
+#ifndef RTW_HEADER_volatile_types_h_
+#define RTW_HEADER_volatile_types_h_
+
+/* Hardware register type */
+typedef volatile unsigned short reg16_T;
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_volatile_T RT_MODEL_volatile_T;
+
+#endif                                 /* RTW_HEADER_volatile_types_h_ */
@@ -0,0 +1,48 @@
+/*
+ * File: wrapped.h
+ *
+ * Code generated for Simulink model 'wrapped'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.001
+ */
+
+#ifndef RTW_HEADER_wrapped_h_
+#define RTW_HEADER_wrapped_h_
+#include "rtwtypes.h"
+#include "wrapped_types.h"
+
+#define NUM_ACTUATORS                  6
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T MountCommandedPositionsOfTheSegments[
+    NUM_ACTUATORS];                    /* '<Root>/MountCommandedPositionsOfTheSegments' */
+  real_T gain;                         /* '<Root>/gain'
+                                        * wrapped comment; with a semicolon
+                                        */
+  int32_T
+    mode;                              /* '<Root>/mode' */
+} ExtU_wrapped_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  real_T y[NUM_ACTUATORS            /* '<Root>/y' */
+    ];
+} ExtY_wrapped_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_wrapped_T {
+  const char_T * volatile errorStatus;
+};
+
+/* Model entry point functions */
+extern void wrapped_initialize(RT_MODEL_wrapped_T *const wrapped_M,
+  ExtU_wrapped_T *wrapped_U, ExtY_wrapped_T *wrapped_Y);
+extern void wrapped_step(RT_MODEL_wrapped_T *const wrapped_M, ExtU_wrapped_T
+  *wrapped_U, ExtY_wrapped_T *wrapped_Y);
+
+#endif                                 /* RTW_HEADER_wrapped_h_ */
@@ -0,0 +1,13 @@
+/*
+ * File: wrapped_types.h
+ *
+ * Code generated for Simulink model 'wrapped'.
+ */
+
+#ifndef RTW_HEADER_wrapped_types_h_
+#define RTW_HEADER_wrapped_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_wrapped_T RT_MODEL_wrapped_T;
+
+#endif                                 /* RTW_HEADER_wrapped_types_h_ */
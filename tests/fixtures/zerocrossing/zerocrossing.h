@@ -0,0 +1,55 @@
+/*
+ * File: zerocrossing.h
+ *
+ * Code generated for Simulink model 'zerocrossing'.
+ *
+ * Model version                  : 1.2
+ * Simulink Coder version         : 9.4 (R2020b) 29-Jul-2020
+ * C/C++ source code generated on : Tue Mar 14 09:41:05 2023
+ *
+ * Target selection: ert.tlc
+ * Fixed step size in seconds: 0.01
+ * Solver                         : FixedStepDiscrete
+ */
+
+#ifndef RTW_HEADER_zerocrossing_h_
+#define RTW_HEADER_zerocrossing_h_
+#include "rtwtypes.h"
+#include "zero_crossing_types.h"
+#include "zerocrossing_types.h"
+
+/* Block states (default storage) for system '<Root>' */
+typedef struct {
+  int32_T Counter_DSTATE;              /* '<S1>/Counter' */
+} DW_zerocrossing_T;
+
+/* Zero-crossing (trigger) state */
+typedef struct {
+  ZCSigState Counter_Trig_ZCE;         /* '<Root>/Counter' */
+} PrevZCX_zerocrossing_T;
+
+/* External inputs (root inport signals with default storage) */
+typedef struct {
+  real_T trigger;                      /* '<Root>/trigger' */
+} ExtU_zerocrossing_T;
+
+/* External outputs (root outports fed by signals with default storage) */
+typedef struct {
+  int32_T edges;                       /* '<Root>/edges' */
+} ExtY_zerocrossing_T;
+
+/* Real-time Model Data Structure */
+struct tag_RTM_zerocrossing_T {
+  const char_T * volatile errorStatus;
+  PrevZCX_zerocrossing_T *prevZCSigState;
+  DW_zerocrossing_T *dwork;
+};
+
+/* Model entry point functions */
+extern void zerocrossing_initialize(RT_MODEL_zerocrossing_T *const
+  zerocrossing_M, ExtU_zerocrossing_T *zerocrossing_U, ExtY_zerocrossing_T
+  *zerocrossing_Y);
+extern void zerocrossing_step(RT_MODEL_zerocrossing_T *const zerocrossing_M,
+  ExtU_zerocrossing_T *zerocrossing_U, ExtY_zerocrossing_T *zerocrossing_Y);
+
+#endif                                 /* RTW_HEADER_zerocrossing_h_ */
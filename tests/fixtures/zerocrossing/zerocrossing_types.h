@@ -0,0 +1,13 @@
+/*
+ * File: zerocrossing_types.h
+ *
+ * Code generated for Simulink model 'zerocrossing'.
+ */
+
+#ifndef RTW_HEADER_zerocrossing_types_h_
+#define RTW_HEADER_zerocrossing_types_h_
+
+/* Forward declaration for rtModel */
+typedef struct tag_RTM_zerocrossing_T RT_MODEL_zerocrossing_T;
+
+#endif                                 /* RTW_HEADER_zerocrossing_types_h_ */
@@ -0,0 +1,174 @@
+#![cfg(feature = "std")]
+use simulink_rs::{parse_model_header, parse_model_header_cached, Variable};
+use std::fs::{self, File};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+fn fixture(model: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(model)
+        .join(format!("{}.h", model))
+}
+
+#[test]
+fn array() {
+    let model = parse_model_header(&fixture("array")).unwrap();
+    assert_eq!(model.name, "array");
+    let inputs = model.inputs.unwrap();
+    assert_eq!(
+        inputs[1],
+        Variable {
+            name: "B".to_string(),
+            ctype: "real_T".to_string(),
+            dims: vec![3, 4]
+        }
+    );
+    assert_eq!(model.outputs.unwrap()[0].dims, vec![2]);
+    assert_eq!(model.params[0].name, "Gain");
+}
+
+#[test]
+fn generator() {
+    let model = parse_model_header(&fixture("generator")).unwrap();
+    assert_eq!(model.version.as_deref(), Some("1.2"));
+    assert_eq!(model.sample_time, Some(0.5));
+    assert!(model.inputs.is_none() && model.states.is_none());
+    assert_eq!(model.solver, None);
+}
+
+#[test]
+fn scalar() {
+    let model = parse_model_header(&fixture("scalar")).unwrap();
+    assert_eq!(
+        model.coder_version.as_deref(),
+        Some("9.4 (R2020b) 29-Jul-2020")
+    );
+    assert_eq!(
+        model.codegen_date.as_deref(),
+        Some("Mon Mar  6 14:22:31 2023")
+    );
+    assert_eq!(model.solver.as_deref(), Some("FixedStepDiscrete"));
+    assert_eq!(
+        model.checksum,
+        Some([0x1A2B3C4D, 0, 0xDEADBEEF, 0x12345678])
+    );
+}
+
+#[test]
+fn bus() {
+    let model = parse_model_header(&fixture("bus")).unwrap();
+    let names: Vec<_> = model
+        .inputs
+        .unwrap()
+        .into_iter()
+        .map(|variable| variable.name)
+        .collect();
+    assert_eq!(names, ["u", "bus.signal", "bus.count", "bus.sub.v"]);
+}
+
+// The names of the `variables`
+fn names(variables: Option<Vec<Variable>>) -> Vec<String> {
+    variables
+        .unwrap()
+        .into_iter()
+        .map(|variable| variable.name)
+        .collect()
+}
+
+#[test]
+fn legacy() {
+    let model = parse_model_header(&fixture("legacy")).unwrap();
+    assert_eq!(names(model.states), ["UnitDelay_DSTATE"]);
+    assert_eq!(names(model.inputs), ["u"]);
+    assert_eq!(names(model.outputs), ["y"]);
+    assert_eq!(model.params[0].name, "Gain");
+}
+
+#[test]
+fn relabeled() {
+    let model = parse_model_header(&fixture("relabeled")).unwrap();
+    assert_eq!(
+        names(model.states),
+        ["Integrator_DSTATE", "Integrator_PrevResetState"]
+    );
+    assert_eq!(names(model.inputs), ["u", "reset"]);
+    assert_eq!(model.outputs.unwrap()[0].dims, vec![2]);
+    assert!(model.params.is_empty());
+}
+
+#[test]
+fn malformed() {
+    let e = parse_model_header(&fixture("malformed")).unwrap_err();
+    assert!(e.to_string().contains("`File: <model>.h`"));
+}
+
+#[test]
+fn stripped() {
+    let model = parse_model_header(&fixture("stripped")).unwrap();
+    assert_eq!(model.name, "stripped");
+}
+
+#[test]
+fn wrapped() {
+    let model = parse_model_header(&fixture("wrapped")).unwrap();
+    let inputs = model.inputs.unwrap();
+    let names: Vec<_> = inputs.iter().map(|input| input.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["MountCommandedPositionsOfTheSegments", "gain", "mode"]
+    );
+    assert_eq!(inputs[0].dims, vec![6]);
+    assert_eq!(inputs[2].ctype, "int32_T");
+    assert_eq!(model.outputs.unwrap()[0].dims, vec![6]);
+}
+
+#[test]
+fn cache() {
+    // a directory of its own for each run, the tests run in parallel in several checkouts
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!(
+        "simulink-binder-parse-cache-{}-{}",
+        std::process::id(),
+        nanos
+    ));
+    let (sys, out_dir) = (dir.join("sys"), dir.join("out"));
+    fs::create_dir_all(&sys).unwrap();
+    fs::create_dir_all(&out_dir).unwrap();
+    let header = sys.join("generator.h");
+    fs::copy(fixture("generator"), &header).unwrap();
+    let mtime = fs::metadata(&header).unwrap().modified().unwrap();
+    assert_eq!(
+        parse_model_header_cached(&header, &out_dir)
+            .unwrap()
+            .version
+            .as_deref(),
+        Some("1.2")
+    );
+    assert!(out_dir.join("generator_model.cache").exists());
+    // the cached model is used while the header modification time is unchanged
+    let edited = fs::read_to_string(&header).unwrap().replace("1.2", "1.3");
+    fs::write(&header, edited).unwrap();
+    let file = File::options().write(true).open(&header).unwrap();
+    file.set_modified(mtime).unwrap();
+    assert_eq!(
+        parse_model_header_cached(&header, &out_dir)
+            .unwrap()
+            .version
+            .as_deref(),
+        Some("1.2")
+    );
+    // and parsed again when the modification time changes
+    file.set_modified(mtime + Duration::from_secs(1)).unwrap();
+    assert_eq!(
+        parse_model_header_cached(&header, &out_dir)
+            .unwrap()
+            .version
+            .as_deref(),
+        Some("1.3")
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
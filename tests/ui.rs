@@ -0,0 +1,50 @@
+use std::{env, fs, path::Path};
+
+// Copies the fixtures directory into `dst`
+fn copy_dir(src: &Path, dst: &Path) {
+    fs::create_dir_all(dst).unwrap();
+    for entry in fs::read_dir(src).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            copy_dir(&path, &dst.join(entry.file_name()));
+        } else {
+            fs::copy(&path, dst.join(entry.file_name())).unwrap();
+        }
+    }
+}
+
+#[test]
+fn ui() {
+    // the macro reads the headers relative to the root of the trybuild project
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target = env::var_os("CARGO_TARGET_DIR").map_or_else(|| root.join("target"), Into::into);
+    let project = target
+        .join("tests")
+        .join("trybuild")
+        .join(env!("CARGO_PKG_NAME"));
+    copy_dir(
+        &root.join("tests").join("fixtures"),
+        &project.join("fixtures"),
+    );
+
+    let t = trybuild::TestCases::new();
+    if cfg!(feature = "std") {
+        t.pass("tests/ui/pass/*.rs");
+        t.compile_fail("tests/ui/fail/*.rs");
+    } else {
+        t.pass("tests/ui/no_std/*.rs");
+    }
+    drop(t);
+
+    // the macros writing the bindings include `<OUT_DIR>/bindings.rs`, written by the build
+    // script of the crate and here left empty, the fixtures declare the bindgen bindings;
+    // the models directory without a `dir` argument is the two models `feedback` fixture
+    if cfg!(feature = "std") {
+        let out_dir = project.join("out");
+        fs::create_dir_all(&out_dir).unwrap();
+        fs::write(out_dir.join("bindings.rs"), "").unwrap();
+        env::set_var("OUT_DIR", &out_dir);
+        env::set_var("SIMULINK_BINDER_DIR", "fixtures/feedback");
+        trybuild::TestCases::new().pass("tests/ui/bindings/*.rs");
+    }
+}
@@ -0,0 +1,87 @@
+// Enumerations ordered by their C values with the `enum_ord` flag
+#![allow(non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+// bindgen bindings of `fixtures/plant/plant.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub const Status_T_Idle: Status_T = 1;
+pub const Status_T_Busy: Status_T = 2;
+pub type Status_T = ::std::os::raw::c_uint;
+pub type RT_MODEL_plant_T = tag_RTM_plant_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_plant_T {
+    pub xdot: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct X_plant_T {
+    pub Integrator_CSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_plant_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_plant_T {
+    pub y: real_T,
+    pub status: Status_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_plant_T {
+    pub errorStatus: *const char_T,
+    pub contStates: *mut X_plant_T,
+    pub derivs: *mut real_T,
+    pub dwork: *mut DW_plant_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn plant_initialize(
+    plant_M: *mut RT_MODEL_plant_T,
+    _plant_U: *mut ExtU_plant_T,
+    plant_Y: *mut ExtY_plant_T,
+) {
+    (*(*plant_M).contStates).Integrator_CSTATE = 1.0;
+    (*plant_Y).status = Status_T_Idle;
+}
+#[no_mangle]
+pub unsafe extern "C" fn plant_derivatives(
+    plant_M: *mut RT_MODEL_plant_T,
+    plant_U: *mut ExtU_plant_T,
+) {
+    (*(*plant_M).dwork).xdot = (*plant_U).u;
+}
+#[no_mangle]
+pub unsafe extern "C" fn plant_step(
+    plant_M: *mut RT_MODEL_plant_T,
+    plant_U: *mut ExtU_plant_T,
+    plant_Y: *mut ExtY_plant_T,
+) {
+    plant_derivatives(plant_M, plant_U);
+    let x = &mut *(*plant_M).contStates;
+    x.Integrator_CSTATE += 0.5 * (*(*plant_M).dwork).xdot;
+    (*plant_Y).y = x.Integrator_CSTATE;
+    (*plant_Y).status = Status_T_Busy;
+}
+
+simulink_rs::import! {Plant, "fixtures/plant/plant.h", enum_ord}
+
+fn main() {
+    assert!(Status_TEnum::Idle < Status_TEnum::Busy);
+    let mut modes = [Status_TEnum::Busy, Status_TEnum::Idle];
+    modes.sort();
+    assert_eq!(modes, [Status_TEnum::Idle, Status_TEnum::Busy]);
+    let mut plant = Plant::new();
+    assert_eq!(
+        Status_TEnum::try_from(plant.outputs.status),
+        Ok(Status_TEnum::Idle)
+    );
+    plant.step();
+    let status = Status_TEnum::try_from(plant.outputs.status).unwrap();
+    assert_eq!(status.max(Status_TEnum::Idle), Status_TEnum::Busy);
+    assert_eq!(Status_T::from(status), Status_T_Busy);
+}
@@ -0,0 +1,107 @@
+// Two models of the same directory sharing the bindings written once by `import_bindings!`
+#![allow(non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+// bindgen bindings of `fixtures/feedback/plant.h` and `fixtures/feedback/controller.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub const Mode_T_Hold: Mode_T = 0;
+pub const Mode_T_Track: Mode_T = 1;
+pub type Mode_T = ::std::os::raw::c_uint;
+pub type RT_MODEL_plant_T = tag_RTM_plant_T;
+pub type RT_MODEL_controller_T = tag_RTM_controller_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_plant_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_plant_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_plant_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_plant_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_plant_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_controller_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_controller_T {
+    pub u: real_T,
+    pub mode: Mode_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_controller_T {
+    pub errorStatus: *const char_T,
+}
+
+// the models C source
+#[no_mangle]
+pub unsafe extern "C" fn plant_initialize(
+    plant_M: *mut RT_MODEL_plant_T,
+    _plant_U: *mut ExtU_plant_T,
+    plant_Y: *mut ExtY_plant_T,
+) {
+    (*(*plant_M).dwork).Integrator_DSTATE = 1.0;
+    (*plant_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn plant_step(
+    plant_M: *mut RT_MODEL_plant_T,
+    plant_U: *mut ExtU_plant_T,
+    plant_Y: *mut ExtY_plant_T,
+) {
+    let dw = &mut *(*plant_M).dwork;
+    (*plant_Y).y = dw.Integrator_DSTATE;
+    dw.Integrator_DSTATE += (*plant_U).u;
+}
+#[no_mangle]
+pub unsafe extern "C" fn controller_initialize(
+    _controller_M: *mut RT_MODEL_controller_T,
+    _controller_U: *mut ExtU_controller_T,
+    controller_Y: *mut ExtY_controller_T,
+) {
+    (*controller_Y).u = 0.0;
+    (*controller_Y).mode = Mode_T_Hold;
+}
+#[no_mangle]
+pub unsafe extern "C" fn controller_step(
+    _controller_M: *mut RT_MODEL_controller_T,
+    controller_U: *mut ExtU_controller_T,
+    controller_Y: *mut ExtY_controller_T,
+) {
+    let y = (*controller_U).y;
+    (*controller_Y).u = -0.5 * y;
+    (*controller_Y).mode = if y != 0.0 { Mode_T_Track } else { Mode_T_Hold };
+}
+
+simulink_rs::import_bindings!();
+simulink_rs::import! {Plant, model = "plant", bindings = false}
+simulink_rs::import! {Controller, model = "controller", bindings = false}
+
+fn main() {
+    // the wrappers are named after the models
+    let (mut plant, mut controller): (plant, controller) = (Plant::new(), Controller::new());
+    plant.step();
+    controller.inputs.y = plant.outputs.y;
+    controller.step();
+    assert_eq!(controller.outputs.u, -0.5);
+    // the enumerations of the directory headers
+    assert_eq!(
+        Mode_TEnum::try_from(controller.outputs.mode),
+        Ok(Mode_TEnum::Track)
+    );
+    assert_eq!(Mode_T::from(Mode_TEnum::Hold), Mode_T_Hold);
+}
@@ -0,0 +1,4 @@
+// Two model headers in the `dir` directory
+simulink_rs::import! {Mixer, dir = "fixtures/ambiguous", bindings = false}
+
+fn main() {}
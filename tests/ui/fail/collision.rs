@@ -0,0 +1,40 @@
+// Two outputs sanitized into the same accessor identifier
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/collision/collision.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_collision_T = tag_RTM_collision_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_collision_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_collision_T {
+    pub fooBar: real_T,
+    pub foo_bar: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_collision_T {
+    pub errorStatus: *const char_T,
+}
+
+extern "C" {
+    pub fn collision_initialize(
+        collision_M: *mut RT_MODEL_collision_T,
+        collision_U: *mut ExtU_collision_T,
+        collision_Y: *mut ExtY_collision_T,
+    );
+    pub fn collision_step(
+        collision_M: *mut RT_MODEL_collision_T,
+        collision_U: *mut ExtU_collision_T,
+        collision_Y: *mut ExtY_collision_T,
+    );
+}
+
+simulink_rs::import! {Collision, "fixtures/collision/collision.h", bindings = false}
+
+fn main() {}
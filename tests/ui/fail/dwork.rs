@@ -0,0 +1,49 @@
+// The wrapper of states with a pointer member isn't `Clone`
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/dwork/dwork.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_dwork_T = tag_RTM_dwork_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_dwork_T {
+    pub Delay_DSTATE: real_T,
+    pub Buffer_PWORK: *mut real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_dwork_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_dwork_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_dwork_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_dwork_T,
+}
+
+extern "C" {
+    pub fn dwork_initialize(
+        dwork_M: *mut RT_MODEL_dwork_T,
+        dwork_U: *mut ExtU_dwork_T,
+        dwork_Y: *mut ExtY_dwork_T,
+    );
+    pub fn dwork_step(
+        dwork_M: *mut RT_MODEL_dwork_T,
+        dwork_U: *mut ExtU_dwork_T,
+        dwork_Y: *mut ExtY_dwork_T,
+    );
+}
+
+simulink_rs::import! {Dwork, "fixtures/dwork/dwork.h", bindings = false}
+
+fn main() {
+    let dwork = Dwork::new();
+    let _copy = dwork.clone();
+}
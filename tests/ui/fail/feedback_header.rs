@@ -0,0 +1,4 @@
+// The feedback models headers are named after the wrappers
+simulink_rs::feedback! {Plant, Controller, model = "plant"}
+
+fn main() {}
@@ -0,0 +1,51 @@
+// Bindings fields out of the order of the header fields
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/scalar/scalar.h`
+pub type real_T = f64;
+pub type real32_T = f32;
+pub type int32_T = i32;
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_scalar_T = tag_RTM_scalar_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_scalar_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_scalar_T {
+    pub g: real32_T,
+    pub u: real_T,
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_scalar_T {
+    pub y: real_T,
+    pub count: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_scalar_T {
+    pub errorStatus: *const char_T,
+    pub work: *mut DW_scalar_T,
+}
+
+extern "C" {
+    pub fn scalar_initialize(
+        scalar_M: *mut RT_MODEL_scalar_T,
+        scalar_U: *mut ExtU_scalar_T,
+        scalar_Y: *mut ExtY_scalar_T,
+    );
+    pub fn scalar_step(
+        scalar_M: *mut RT_MODEL_scalar_T,
+        scalar_U: *mut ExtU_scalar_T,
+        scalar_Y: *mut ExtY_scalar_T,
+    );
+}
+
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false}
+
+fn main() {}
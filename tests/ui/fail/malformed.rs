@@ -0,0 +1,4 @@
+// A header without the `File:` line nor the structure tags
+simulink_rs::import! {Malformed, "fixtures/malformed/malformed.h", bindings = false}
+
+fn main() {}
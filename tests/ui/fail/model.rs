@@ -0,0 +1,4 @@
+// A model name without header in the `dir` directory
+simulink_rs::import! {Center, dir = "fixtures/ambiguous", model = "center", bindings = false}
+
+fn main() {}
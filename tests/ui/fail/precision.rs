@@ -0,0 +1,4 @@
+// A precision other than `f64` or `f32`
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false, precision = f16}
+
+fn main() {}
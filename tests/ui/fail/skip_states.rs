@@ -0,0 +1,4 @@
+// Skipping an output
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false, skip_states = [Integrator_DSTATE, y]}
+
+fn main() {}
@@ -0,0 +1,4 @@
+// An array size expression that isn't integer arithmetic of `+`, `-` and `*`
+simulink_rs::import! {Unevaluated, "fixtures/unevaluated/unevaluated.h", bindings = false}
+
+fn main() {}
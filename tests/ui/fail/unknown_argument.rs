@@ -0,0 +1,4 @@
+// An argument the macro doesn't know
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false, fast}
+
+fn main() {}
@@ -0,0 +1,41 @@
+// A member declaration that isn't parsed as a variable, denied to fail the build
+#![deny(deprecated)]
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/unparsed/unparsed.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_unparsed_T = tag_RTM_unparsed_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_unparsed_T {
+    pub u: real_T,
+    pub gain: f64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_unparsed_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_unparsed_T {
+    pub errorStatus: *const char_T,
+}
+
+extern "C" {
+    pub fn unparsed_initialize(
+        unparsed_M: *mut RT_MODEL_unparsed_T,
+        unparsed_U: *mut ExtU_unparsed_T,
+        unparsed_Y: *mut ExtY_unparsed_T,
+    );
+    pub fn unparsed_step(
+        unparsed_M: *mut RT_MODEL_unparsed_T,
+        unparsed_U: *mut ExtU_unparsed_T,
+        unparsed_Y: *mut ExtY_unparsed_T,
+    );
+}
+
+simulink_rs::import! {Unparsed, "fixtures/unparsed/unparsed.h", bindings = false}
+
+fn main() {}
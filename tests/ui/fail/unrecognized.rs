@@ -0,0 +1,44 @@
+// Members of types not declared in the headers, denied to fail the build
+#![deny(deprecated)]
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/unrecognized/unrecognized.h`
+pub type real_T = f64;
+pub type gain_T = f64;
+pub type tick_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_unrecognized_T = tag_RTM_unrecognized_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_unrecognized_T {
+    pub u: real_T,
+    pub gain: gain_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_unrecognized_T {
+    pub y: real_T,
+    pub count: tick_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_unrecognized_T {
+    pub errorStatus: *const char_T,
+}
+
+extern "C" {
+    pub fn unrecognized_initialize(
+        unrecognized_M: *mut RT_MODEL_unrecognized_T,
+        unrecognized_U: *mut ExtU_unrecognized_T,
+        unrecognized_Y: *mut ExtY_unrecognized_T,
+    );
+    pub fn unrecognized_step(
+        unrecognized_M: *mut RT_MODEL_unrecognized_T,
+        unrecognized_U: *mut ExtU_unrecognized_T,
+        unrecognized_Y: *mut ExtY_unrecognized_T,
+    );
+}
+
+simulink_rs::import! {Unrecognized, "fixtures/unrecognized/unrecognized.h", bindings = false}
+
+fn main() {}
@@ -0,0 +1,4 @@
+// An array size not defined in the header
+simulink_rs::import! {Unresolved, "fixtures/unresolved/unresolved.h", bindings = false}
+
+fn main() {}
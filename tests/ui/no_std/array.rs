@@ -0,0 +1,104 @@
+// `no_std` wrapper of the 1-D and 2-D arrays model
+#![no_std]
+#![allow(non_camel_case_types, non_snake_case)]
+
+// the test harness only, the wrapper is compiled without the `std` prelude and paths
+extern crate std as host;
+
+// bindgen bindings of `fixtures/array/array.h`
+pub type real_T = f64;
+pub type uint16_T = u16;
+pub type boolean_T = u8;
+pub type P_array_T = P_array_T_;
+pub type RT_MODEL_array_T = tag_RTM_array_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_array_T {
+    pub Delay_DSTATE: [real_T; 3usize],
+    pub Scratch_DWORK: [real_T; 8usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_array_T {
+    pub A: [real_T; 3usize],
+    pub B: [[real_T; 4usize]; 3usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_array_T {
+    pub y: [real_T; 2usize],
+    pub n: uint16_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct P_array_T_ {
+    pub Gain: real_T,
+    pub K: [[real_T; 3usize]; 2usize],
+    pub Enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_array_T {
+    pub defaultParam: *mut P_array_T,
+    pub dwork: *mut DW_array_T,
+}
+
+// the model C source
+#[no_mangle]
+pub static mut array_P: P_array_T = P_array_T_ {
+    Gain: 2.0,
+    K: [[1.0, 2.0, 3.0], [4.0, 5.0, -6.0]],
+    Enable: 1,
+};
+#[no_mangle]
+pub unsafe extern "C" fn array_initialize(
+    array_M: *mut RT_MODEL_array_T,
+    _array_U: *mut ExtU_array_T,
+    array_Y: *mut ExtY_array_T,
+) {
+    (*array_Y).y[0] = (*(*array_M).defaultParam).Gain;
+    (*array_Y).n = 0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn array_step(
+    array_M: *mut RT_MODEL_array_T,
+    array_U: *mut ExtU_array_T,
+    array_Y: *mut ExtY_array_T,
+) {
+    let gain = (*(*array_M).defaultParam).Gain;
+    let (u, y) = (&*array_U, &mut *array_Y);
+    y.y[0] = gain * u.A.iter().sum::<f64>();
+    y.y[1] = gain * u.B.iter().flatten().sum::<f64>();
+    (*(*array_M).dwork).Delay_DSTATE = u.A;
+    (*(*array_M).dwork).Scratch_DWORK = [y.y[1]; 8];
+    y.n += 1;
+}
+
+
+simulink_rs::import! {Array, "fixtures/array/array.h", bindings = false}
+
+fn main() {
+    let mut array = Array::new();
+    assert_eq!(array.y()[0], 2.0);
+    let mut flat = [1.0; Array::INPUT_LEN];
+    flat[14] = 3.0;
+    array.set_inputs(&flat).unwrap();
+    assert_eq!(
+        array.set_a_at(3, 4.0),
+        Err(simulink_rs::SimulinkError::IndexOutOfBounds {
+            field: "A",
+            index: 3,
+            len: 3
+        })
+    );
+    array.step();
+    assert_eq!(array.y(), &[6.0, 28.0]);
+    assert_eq!(array.signals().nth(15), Some(("y[0]", 6.0)));
+    let snapshot = array.snapshot();
+    array.step();
+    array.restore(&snapshot);
+    assert_eq!(array.outputs.n, 1);
+    assert_eq!(host::format!("{}", array).lines().count(), 4);
+    array.reset();
+    assert_eq!(array.outputs.n, 0);
+}
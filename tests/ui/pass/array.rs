@@ -0,0 +1,184 @@
+// 1-D and 2-D arrays, `#define` array size and parameters
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/array/array.h`
+pub type real_T = f64;
+pub type uint16_T = u16;
+pub type boolean_T = u8;
+pub type P_array_T = P_array_T_;
+pub type RT_MODEL_array_T = tag_RTM_array_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_array_T {
+    pub Delay_DSTATE: [real_T; 3usize],
+    pub Scratch_DWORK: [real_T; 8usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_array_T {
+    pub A: [real_T; 3usize],
+    pub B: [[real_T; 4usize]; 3usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_array_T {
+    pub y: [real_T; 2usize],
+    pub n: uint16_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct P_array_T_ {
+    pub Gain: real_T,
+    pub K: [[real_T; 3usize]; 2usize],
+    pub Enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_array_T {
+    pub defaultParam: *mut P_array_T,
+    pub dwork: *mut DW_array_T,
+}
+
+// the model C source
+#[no_mangle]
+pub static mut array_P: P_array_T = P_array_T_ {
+    Gain: 2.0,
+    K: [[1.0, 2.0, 3.0], [4.0, 5.0, -6.0]],
+    Enable: 1,
+};
+#[no_mangle]
+pub unsafe extern "C" fn array_initialize(
+    array_M: *mut RT_MODEL_array_T,
+    _array_U: *mut ExtU_array_T,
+    array_Y: *mut ExtY_array_T,
+) {
+    (*array_Y).y[0] = (*(*array_M).defaultParam).Gain;
+    (*array_Y).n = 0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn array_step(
+    array_M: *mut RT_MODEL_array_T,
+    array_U: *mut ExtU_array_T,
+    array_Y: *mut ExtY_array_T,
+) {
+    let gain = (*(*array_M).defaultParam).Gain;
+    let (u, y) = (&*array_U, &mut *array_Y);
+    y.y[0] = gain * u.A.iter().sum::<f64>();
+    y.y[1] = gain * u.B.iter().flatten().sum::<f64>();
+    (*(*array_M).dwork).Delay_DSTATE = u.A;
+    (*(*array_M).dwork).Scratch_DWORK = [y.y[1]; 8];
+    y.n += 1;
+}
+
+simulink_rs::import! {Array, "fixtures/array/array.h", bindings = false, skip_states = [Scratch_DWORK]}
+
+fn main() {
+    assert_eq!(Array::INPUT_LEN, 15);
+    assert_eq!(Array::OUTPUT_LEN, 3);
+    assert_eq!(Array::INPUT_LAYOUT, &[("A", 3), ("B", 12)]);
+    assert_eq!(Array::OUTPUT_LAYOUT, &[("y", 2), ("n", 1)]);
+    assert_eq!(Array::sample_time(), None);
+    let defaults = P_array_T::default();
+    assert_eq!((defaults.Gain, defaults.K[1], defaults.Enable), (2.0, [4.0, 5.0, -6.0], 1));
+    let params = P_array_T_ {
+        Gain: 3.0,
+        ..defaults
+    };
+    assert_eq!(Array::with_parameters(params).y()[0], 3.0);
+    let mut array = Array::new();
+    assert_eq!(array.parameters.Gain, 2.0);
+    assert_eq!(array.y()[0], 2.0);
+    assert_eq!(Array::PARAMETER_LEN, 8);
+    let tuned = [4.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5, 0.0];
+    let params = P_array_T::try_from(&tuned[..]).unwrap();
+    assert_eq!(
+        (params.Gain, params.K, params.Enable),
+        (4.0, [[1.0, 0.0, 0.0], [0.0, 1.0, 0.5]], 0)
+    );
+    assert_eq!(
+        array.set_parameters(&tuned[1..]),
+        Err(simulink_rs::SimulinkError::WrongParametersLength {
+            expected: 8,
+            got: 7
+        })
+    );
+    assert_eq!(array.parameters.Gain, 2.0);
+    array.set_parameters(&tuned).unwrap();
+    array.reset();
+    assert_eq!(array.y()[0], 4.0);
+    array
+        .set_parameters(&[2.0, 1.0, 2.0, 3.0, 4.0, 5.0, -6.0, 1.0])
+        .unwrap();
+    array.reset();
+    assert_eq!(array.parameters.K, P_array_T::default().K);
+    let mut flat = vec![1.0; Array::INPUT_LEN];
+    flat[14] = 3.0;
+    array.set_inputs(&flat).unwrap();
+    assert!(array.set_inputs(&flat[1..]).is_err());
+    assert_eq!(array.inputs.B[2][3], 3.0);
+    assert_eq!(
+        array.set_a(&[2.0; 2]),
+        Err(simulink_rs::SimulinkError::WrongLength {
+            field: "A",
+            expected: 3,
+            got: 2
+        })
+    );
+    assert_eq!(array.inputs.A, [1.0; 3]);
+    let mut b = [[1.0; 4]; 3];
+    b[2][3] = 3.0;
+    array.set_b(&b).unwrap();
+    assert!(array.set_b(&b[1..]).is_err());
+    array.set_a_at(1, 4.0).unwrap();
+    assert_eq!(array.inputs.A, [1.0, 4.0, 1.0]);
+    assert_eq!(
+        array.set_a_at(3, 4.0),
+        Err(simulink_rs::SimulinkError::IndexOutOfBounds {
+            field: "A",
+            index: 3,
+            len: 3
+        })
+    );
+    array.set_b_at(0, [1.0; 4]).unwrap();
+    array.set_a_at(1, 1.0).unwrap();
+    assert_ne!(array.inputs, ExtU_array_T::default());
+    array.step();
+    assert_eq!(array.y(), &[6.0, 28.0]);
+    assert_eq!((array.y_at(1), array.y_at(2)), (Some(28.0), None));
+    assert_eq!(array.outputs_named()[1], ("y[1]", 28.0));
+    assert_eq!(array.outputs_named()[2], ("n", 1.0));
+    assert_eq!(array.inputs_named()[3], ("B[0][0]", 1.0));
+    let inputs = ExtU_array_T::builder().a([1.0, 4.0, 1.0]).build();
+    assert_eq!((inputs.A, inputs.B), ([1.0, 4.0, 1.0], [[0.0; 4]; 3]));
+    let b = ExtU_array_T::builder().b([[1.0; 4]; 3]).build().B;
+    assert_eq!(Array::with_inputs(inputs).inputs.A, [1.0, 4.0, 1.0]);
+    assert_eq!(b, [[1.0; 4]; 3]);
+    let snapshot = array.snapshot();
+    assert_eq!(snapshot.states.Delay_DSTATE, [1.0; 3]);
+    array.inputs.A = [2.0; 3];
+    array.step();
+    array.restore(&snapshot);
+    assert_eq!(array.inputs.A, [1.0; 3]);
+    assert_eq!(array.outputs_named()[2], ("n", 1.0));
+    array.step();
+    assert_eq!(array.y(), &[6.0, 28.0]);
+    assert_eq!(array.outputs[ArrayOutputArray::Y], [6.0, 28.0]);
+    assert_eq!(array.outputs[ArrayOutput::N], array.outputs.n);
+    array.inputs[ArrayInputArray::B][5] = 7.0;
+    assert_eq!(array.inputs.B[1][1], 7.0);
+    assert_eq!(array.inputs[ArrayInputArray::B].len(), 12);
+    array.inputs[ArrayInputArray::A].copy_from_slice(&[1.0, 2.0, 3.0]);
+    assert_eq!(array.inputs.A, [1.0, 2.0, 3.0]);
+    assert_eq!(ArrayInputArray::ALL.map(|input| input.name()), ["A", "B"]);
+    #[cfg(feature = "nalgebra")]
+    {
+        assert_eq!(array.y_vector().sum(), 34.0);
+        assert_eq!(array.b_matrix()[(2, 3)], 3.0);
+        assert_eq!(array.b_matrix().row(0).sum(), 4.0);
+        array.b_matrix_mut()[(1, 2)] = 5.0;
+        assert_eq!(array.inputs.B[1][2], 5.0);
+        array.a_vector_mut().fill(0.0);
+        assert_eq!(array.inputs.A, [0.0; 3]);
+        assert_eq!(array.a_vector().len(), 3);
+    }
+}
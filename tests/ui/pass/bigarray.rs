@@ -0,0 +1,82 @@
+// Serialization round-trip of the inputs, outputs and states with arrays longer than 32 elements
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/bigarray/bigarray.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_bigarray_T = tag_RTM_bigarray_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_bigarray_T {
+    pub K_DSTATE: [[real_T; 40usize]; 6usize],
+    pub Delay_DSTATE: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_bigarray_T {
+    pub u: [real_T; 40usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_bigarray_T {
+    pub y: [real_T; 6usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_bigarray_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_bigarray_T,
+}
+
+// the model C source, the gains accumulate the inputs and the outputs are their products
+#[no_mangle]
+pub unsafe extern "C" fn bigarray_initialize(
+    _bigarray_M: *mut RT_MODEL_bigarray_T,
+    _bigarray_U: *mut ExtU_bigarray_T,
+    _bigarray_Y: *mut ExtY_bigarray_T,
+) {
+}
+#[no_mangle]
+pub unsafe extern "C" fn bigarray_step(
+    bigarray_M: *mut RT_MODEL_bigarray_T,
+    bigarray_U: *mut ExtU_bigarray_T,
+    bigarray_Y: *mut ExtY_bigarray_T,
+) {
+    let (dw, u, y) = (&mut *(*bigarray_M).dwork, &*bigarray_U, &mut *bigarray_Y);
+    for (i, (k, y)) in dw.K_DSTATE.iter_mut().zip(y.y.iter_mut()).enumerate() {
+        for (k, u) in k.iter_mut().zip(u.u) {
+            *k += u * (i + 1) as f64;
+        }
+        *y = k.iter().zip(u.u).map(|(k, u)| k * u).sum();
+    }
+    dw.Delay_DSTATE = [y.y[0], dw.Delay_DSTATE[0]];
+}
+
+simulink_rs::import! {Bigarray, "fixtures/bigarray/bigarray.h", bindings = false}
+
+fn main() {
+    let mut bigarray = Bigarray::new();
+    let inputs: Vec<f64> = (0..40).map(|i| i as f64 * 0.25).collect();
+    bigarray.set_inputs(&inputs).unwrap();
+    bigarray.step_for(3);
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&bigarray.inputs).unwrap();
+        let inputs: ExtU_bigarray_T = serde_json::from_str(&json).unwrap();
+        assert_eq!(inputs.u, bigarray.inputs.u);
+        let json = serde_json::to_string(&bigarray.outputs).unwrap();
+        let outputs: ExtY_bigarray_T = serde_json::from_str(&json).unwrap();
+        assert_eq!(outputs.y, bigarray.outputs.y);
+        let json = serde_json::to_string(&bigarray.states).unwrap();
+        let states: DW_bigarray_T = serde_json::from_str(&json).unwrap();
+        assert_eq!(states.K_DSTATE, bigarray.states.K_DSTATE);
+        assert_eq!(states.Delay_DSTATE, bigarray.states.Delay_DSTATE);
+        // the restored wrapper steps as the original one
+        let json = serde_json::to_string(&bigarray).unwrap();
+        let mut restored: Bigarray = serde_json::from_str(&json).unwrap();
+        bigarray.step();
+        restored.step();
+        assert_eq!(restored.outputs.y, bigarray.outputs.y);
+        assert_eq!(restored.states.K_DSTATE, bigarray.states.K_DSTATE);
+    }
+}
@@ -0,0 +1,89 @@
+// Nested bus signals in the inputs and outputs structures
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/bus/bus.h`
+pub type real_T = f64;
+pub type int32_T = i32;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_bus_T = tag_RTM_bus_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_bus_T {
+    pub u: real_T,
+    pub bus: ExtU_bus_T__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_bus_T__bindgen_ty_1 {
+    pub signal: real_T,
+    pub count: int32_T,
+    pub sub: ExtU_bus_T__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_bus_T__bindgen_ty_1__bindgen_ty_1 {
+    pub v: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_bus_T {
+    pub pose: Pose_tag,
+    pub sum: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Pose_tag {
+    pub x: real_T,
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_bus_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn bus_initialize(
+    _bus_M: *mut RT_MODEL_bus_T,
+    _bus_U: *mut ExtU_bus_T,
+    _bus_Y: *mut ExtY_bus_T,
+) {
+}
+#[no_mangle]
+pub unsafe extern "C" fn bus_step(
+    _bus_M: *mut RT_MODEL_bus_T,
+    bus_U: *mut ExtU_bus_T,
+    bus_Y: *mut ExtY_bus_T,
+) {
+    let (u, y) = (&*bus_U, &mut *bus_Y);
+    y.pose.x = u.bus.sub.v[0];
+    y.pose.y = u.bus.sub.v[1];
+    y.sum = u.u + u.bus.signal + u.bus.count as f64;
+}
+
+simulink_rs::import! {Bus, "fixtures/bus/bus.h", bindings = false}
+
+fn main() {
+    assert_eq!(Bus::INPUT_LEN, 5);
+    assert_eq!(Bus::OUTPUT_LEN, 3);
+    let mut bus = Bus::new();
+    assert_eq!(bus.inputs.bus.sub.v, [0.0; 2]);
+    bus.set_inputs(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+    bus.set_bus_signal(20.0);
+    bus.step();
+    assert_eq!(bus.outputs.sum, 24.0);
+    assert_eq!((bus.pose_x(), bus.pose_y()), (4.0, 5.0));
+    assert_eq!(bus.inputs_named()[3], ("bus.sub.v[0]", 4.0));
+    assert_eq!(bus.outputs_named()[0], ("pose.x", 4.0));
+    assert_eq!(bus.to_string().lines().nth(2), Some("bus.count: 3"));
+    assert_eq!(BusOutput::ALL, [BusOutput::PoseX, BusOutput::PoseY, BusOutput::Sum]);
+    let values: Vec<f64> = BusOutput::ALL.iter().map(|&output| bus.outputs[output]).collect();
+    assert_eq!(values, vec![4.0, 5.0, 24.0]);
+    bus.outputs[BusOutput::PoseY] = -5.0;
+    assert_eq!(bus.pose_y(), -5.0);
+    assert_eq!(BusOutput::PoseX.name(), "pose.x");
+    let names: Vec<_> = BusInput::ALL.iter().map(BusInput::name).collect();
+    assert_eq!(names, ["u", "bus.signal", "bus.count"]);
+    assert_eq!(BusInputArray::ALL, [BusInputArray::BusSubV]);
+}
@@ -0,0 +1,82 @@
+// Inputs, outputs and states on their own cache lines with the `cache_aligned` flag
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/scalar/scalar.h`
+pub type real_T = f64;
+pub type real32_T = f32;
+pub type int32_T = i32;
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_scalar_T = tag_RTM_scalar_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_scalar_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_scalar_T {
+    pub u: real_T,
+    pub g: real32_T,
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_scalar_T {
+    pub y: real_T,
+    pub count: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_scalar_T {
+    pub errorStatus: *const char_T,
+    pub work: *mut DW_scalar_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn scalar_initialize(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    _scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    (*(*scalar_M).work).Integrator_DSTATE = 1.0;
+    (*scalar_Y).y = 0.0;
+    (*scalar_Y).count = 0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn scalar_step(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    let dw = &mut *(*scalar_M).work;
+    let u = &*scalar_U;
+    if u.enable != 0 {
+        dw.Integrator_DSTATE += u.u * u.g as f64;
+    }
+    (*scalar_Y).y = dw.Integrator_DSTATE;
+    (*scalar_Y).count += 1;
+}
+
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false, cache_aligned}
+
+fn main() {
+    assert_eq!(std::mem::align_of::<ScalarAligned<ExtU_scalar_T>>(), 64);
+    let mut scalar = Scalar::new();
+    let addresses = [
+        &*scalar.inputs as *const _ as usize,
+        &*scalar.outputs as *const _ as usize,
+        &*scalar.states as *const _ as usize,
+    ];
+    assert!(addresses.iter().all(|address| address % 64 == 0));
+    // the fields are accessed through the aligned storage
+    scalar.inputs.u = 2.0;
+    scalar.inputs.g = 0.5;
+    scalar.inputs.enable = 1;
+    scalar.step();
+    assert_eq!(scalar.outputs.y, 2.0);
+    assert_eq!(scalar.states.Integrator_DSTATE, 2.0);
+    let outputs: ExtY_scalar_T = *scalar.outputs;
+    assert_eq!(outputs.count, 1);
+}
@@ -0,0 +1,83 @@
+// Panics of the model caught by `try_step` with the `catch_unwind` flag
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/scalar/scalar.h`
+pub type real_T = f64;
+pub type real32_T = f32;
+pub type int32_T = i32;
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_scalar_T = tag_RTM_scalar_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_scalar_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_scalar_T {
+    pub u: real_T,
+    pub g: real32_T,
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_scalar_T {
+    pub y: real_T,
+    pub count: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_scalar_T {
+    pub errorStatus: *const char_T,
+    pub work: *mut DW_scalar_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn scalar_initialize(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    _scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    (*(*scalar_M).work).Integrator_DSTATE = 1.0;
+    (*scalar_Y).y = 0.0;
+    (*scalar_Y).count = 0;
+}
+// the model panics on a NaN input, unwinding out of the `C-unwind` step
+#[no_mangle]
+pub unsafe extern "C-unwind" fn scalar_step(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    let dw = &mut *(*scalar_M).work;
+    let u = &*scalar_U;
+    assert!(!u.u.is_nan(), "NaN input");
+    if u.enable != 0 {
+        dw.Integrator_DSTATE += u.u * u.g as f64;
+    }
+    (*scalar_Y).y = dw.Integrator_DSTATE;
+    (*scalar_Y).count += 1;
+}
+
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false, catch_unwind}
+
+fn main() {
+    std::panic::set_hook(Box::new(|_| {}));
+    let mut scalar = Scalar::new();
+    scalar.inputs.u = 2.0;
+    scalar.inputs.g = 0.5;
+    scalar.inputs.enable = 1;
+    assert!(scalar.try_step().is_ok());
+    assert_eq!(scalar.outputs.y, 2.0);
+    scalar.inputs.u = f64::NAN;
+    let payload = scalar.try_step().unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"NaN input"));
+    // the outputs of the step that panicked are left unchanged
+    assert_eq!(scalar.outputs.y, 2.0);
+    assert_eq!(scalar.outputs.count, 1);
+    scalar.inputs.u = 2.0;
+    assert!(scalar.try_step().is_ok());
+    assert_eq!(scalar.outputs.y, 3.0);
+}
@@ -0,0 +1,111 @@
+// Crash-recovery checkpoint of the inputs, outputs and states
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/scalar/scalar.h`
+pub type real_T = f64;
+pub type real32_T = f32;
+pub type int32_T = i32;
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_scalar_T = tag_RTM_scalar_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_scalar_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_scalar_T {
+    pub u: real_T,
+    pub g: real32_T,
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_scalar_T {
+    pub y: real_T,
+    pub count: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_scalar_T {
+    pub errorStatus: *const char_T,
+    pub work: *mut DW_scalar_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn scalar_initialize(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    _scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    (*(*scalar_M).work).Integrator_DSTATE = 1.0;
+    (*scalar_Y).y = 0.0;
+    (*scalar_Y).count = 0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn scalar_step(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    let dw = &mut *(*scalar_M).work;
+    let u = &*scalar_U;
+    if u.enable != 0 {
+        dw.Integrator_DSTATE += u.u * u.g as f64;
+    }
+    (*scalar_Y).y = dw.Integrator_DSTATE;
+    (*scalar_Y).count += 1;
+}
+
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false}
+
+fn main() {
+    let mut scalar = Scalar::new();
+    scalar.inputs.u = 2.0;
+    scalar.inputs.g = 0.5;
+    scalar.inputs.enable = 1;
+    scalar.step_for(2);
+    let checkpoint = scalar.checkpoint();
+    // the header, the `u`, `g` and `enable` inputs, the `y` and `count` outputs and the state
+    assert_eq!(checkpoint.len(), 8 + 13 + 12 + 8);
+    assert_eq!(checkpoint[..4], Scalar::CHECKPOINT_VERSION.to_le_bytes());
+    assert_eq!(
+        checkpoint[4..8],
+        Scalar::CHECKPOINT_FINGERPRINT.to_le_bytes()
+    );
+
+    let mut recovered = Scalar::new();
+    recovered.restore_checkpoint(&checkpoint).unwrap();
+    assert_eq!(recovered.inputs.u, 2.0);
+    assert_eq!(recovered.outputs, scalar.outputs);
+    assert_eq!(recovered.states.Integrator_DSTATE, 3.0);
+    scalar.step();
+    recovered.step();
+    assert_eq!(recovered.outputs, scalar.outputs);
+
+    let mut fresh = Scalar::new();
+    assert_eq!(
+        fresh.restore_checkpoint(&checkpoint[..40]),
+        Err(ScalarCheckpointError::Length {
+            expected: 41,
+            found: 40
+        })
+    );
+    let mut invalid = checkpoint.clone();
+    invalid[0] ^= 0xff;
+    assert!(matches!(
+        fresh.restore_checkpoint(&invalid),
+        Err(ScalarCheckpointError::Version(_))
+    ));
+    let mut invalid = checkpoint.clone();
+    invalid[4] ^= 0xff;
+    assert!(matches!(
+        fresh.restore_checkpoint(&invalid),
+        Err(ScalarCheckpointError::Fingerprint { .. })
+    ));
+    // the controller is left unchanged by an invalid checkpoint
+    assert_eq!(fresh.outputs.count, 0);
+    assert_eq!(fresh.states.Integrator_DSTATE, 1.0);
+}
@@ -0,0 +1,107 @@
+// Floating point and signed and unsigned integer arrays
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/counts/counts.h`
+pub type real_T = f64;
+pub type int32_T = i32;
+pub type uint16_T = u16;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_counts_T = tag_RTM_counts_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_counts_T {
+    pub u: [real_T; 3usize],
+    pub offsets: [int32_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_counts_T {
+    pub counts: [uint16_T; 4usize],
+    pub total: [[int32_T; 2usize]; 2usize],
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_counts_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn counts_initialize(
+    _counts_M: *mut RT_MODEL_counts_T,
+    _counts_U: *mut ExtU_counts_T,
+    counts_Y: *mut ExtY_counts_T,
+) {
+    (*counts_Y).counts = [0; 4];
+}
+#[no_mangle]
+pub unsafe extern "C" fn counts_step(
+    _counts_M: *mut RT_MODEL_counts_T,
+    counts_U: *mut ExtU_counts_T,
+    counts_Y: *mut ExtY_counts_T,
+) {
+    let (u, y) = (&*counts_U, &mut *counts_Y);
+    for (count, u) in y.counts.iter_mut().zip(u.u) {
+        *count += (u > 0.0) as u16;
+    }
+    y.counts[3] += 1;
+    y.total = [u.offsets, [u.offsets[0] + u.offsets[1], -1]];
+    y.y = u.u.iter().sum();
+}
+
+simulink_rs::import! {Counts, "fixtures/counts/counts.h", bindings = false}
+
+fn main() {
+    assert_eq!(ExtY_counts_T::default().counts, [0u16; 4]);
+    assert_eq!(ExtU_counts_T::default().offsets, [0i32; 2]);
+    let mut counts = Counts::new();
+    counts.set_inputs(&[1.0, -1.0, 2.0, 7.0, -3.0]).unwrap();
+    assert_eq!(counts.inputs.offsets, [7, -3]);
+    counts.step_for(2);
+    let typed: (&[u16; 4], &[[i32; 2]; 2], f64) = (counts.counts(), counts.total(), counts.y());
+    assert_eq!(typed, (&[2, 0, 2, 2], &[[7, -3], [4, -1]], 2.0));
+    assert_eq!(counts.counts_at(3), Some(2u16));
+    assert_eq!(counts.total_at(1), Some([4, -1]));
+    assert_eq!(
+        Vec::from(&counts.outputs),
+        vec![2.0, 0.0, 2.0, 2.0, 7.0, -3.0, 4.0, -1.0, 2.0]
+    );
+    let (inputs, outputs) = (counts.inputs_map(), counts.outputs_map());
+    for (map, layout) in [(&inputs, Counts::INPUT_LAYOUT), (&outputs, Counts::OUTPUT_LAYOUT)] {
+        assert_eq!(map.len(), layout.iter().map(|(_, n)| n).sum::<usize>());
+        for (name, n) in layout {
+            let keys = map
+                .keys()
+                .filter(|key| key.split('[').next() == Some(name))
+                .count();
+            assert_eq!(keys, *n);
+        }
+    }
+    assert_eq!(inputs["offsets[1]"], -3.0);
+    assert_eq!((outputs["total[1][0]"], outputs["y"]), (4.0, 2.0));
+    #[cfg(feature = "approx_eq")]
+    {
+        let mut expected = counts.outputs;
+        expected.y += 1e-12;
+        assert_ne!(counts.outputs, expected);
+        assert!(counts.outputs.approx_eq(&expected));
+        expected.counts[1] = 1;
+        assert!(!counts.outputs.approx_eq(&expected));
+    }
+    #[cfg(feature = "approx")]
+    {
+        use simulink_rs::approx::{abs_diff_eq, abs_diff_ne, assert_abs_diff_eq, AbsDiffEq};
+        let mut expected = counts.outputs;
+        expected.y += 1e-12;
+        assert_abs_diff_eq!(counts.outputs, expected, epsilon = 1e-9);
+        assert!(abs_diff_ne!(counts.outputs, expected));
+        expected.y = 2.0;
+        expected.counts[1] = 1;
+        assert!(!counts.outputs.abs_diff_eq(&expected, 1e3));
+        let mut inputs = counts.inputs;
+        inputs.u[2] -= 1e-6;
+        assert!(abs_diff_eq!(counts.inputs, inputs, epsilon = 1e-5));
+        assert!(!abs_diff_eq!(counts.inputs, inputs, epsilon = 1e-7));
+    }
+}
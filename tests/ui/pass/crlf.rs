@@ -0,0 +1,54 @@
+// Header with Windows line endings and indented typedefs
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/crlf/crlf.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_crlf_T = tag_RTM_crlf_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_crlf_T {
+    pub u: real_T,
+    pub v: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_crlf_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_crlf_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn crlf_initialize(
+    _crlf_M: *mut RT_MODEL_crlf_T,
+    _crlf_U: *mut ExtU_crlf_T,
+    crlf_Y: *mut ExtY_crlf_T,
+) {
+    (*crlf_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn crlf_step(
+    _crlf_M: *mut RT_MODEL_crlf_T,
+    crlf_U: *mut ExtU_crlf_T,
+    crlf_Y: *mut ExtY_crlf_T,
+) {
+    let u = &*crlf_U;
+    (*crlf_Y).y = u.u + u.v[0] + u.v[1];
+}
+
+simulink_rs::import! {Crlf, "fixtures/crlf/crlf.h", bindings = false}
+
+fn main() {
+    assert_eq!(Crlf::SAMPLE_TIME, 0.1);
+    assert_eq!(Crlf::INPUT_LEN, 3);
+    assert_eq!(Crlf::OUTPUT_LEN, 1);
+    let mut crlf = Crlf::new();
+    crlf.set_inputs(&[1.0, 2.0, 3.0]).unwrap();
+    crlf.step();
+    assert_eq!(crlf.outputs.y, 6.0);
+}
@@ -0,0 +1,60 @@
+// Inputs dead-band with the `deadband` flag, array inputs element-wise
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/single/single.h`
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_single_T = tag_RTM_single_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_single_T {
+    pub u: [f32; 2usize],
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_single_T {
+    pub y: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_single_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn single_initialize(
+    _single_M: *mut RT_MODEL_single_T,
+    _single_U: *mut ExtU_single_T,
+    single_Y: *mut ExtY_single_T,
+) {
+    (*single_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn single_step(
+    _single_M: *mut RT_MODEL_single_T,
+    single_U: *mut ExtU_single_T,
+    single_Y: *mut ExtY_single_T,
+) {
+    let u = &*single_U;
+    if u.enable != 0 {
+        (*single_Y).y = u.u[0] * u.u[1];
+    }
+}
+
+simulink_rs::import! {Single, "fixtures/single/single.h", bindings = false, deadband}
+
+fn main() {
+    let mut single = Single::new();
+    single.inputs.enable = 1;
+    single.set_u_deadband([0.05, -2.0], 0.1);
+    assert_eq!(single.inputs.u, [0.0, -2.0]);
+    single.step();
+    assert_eq!(single.y(), 0.0);
+    single.set_u_deadband([-0.1, 1.5], 0.1);
+    assert_eq!(single.inputs.u, [0.0, 1.5]);
+    single.set_u_deadband([0.25, 1.5], 0.1);
+    single.step();
+    assert_eq!(single.y(), 0.375);
+}
@@ -0,0 +1,43 @@
+// Model header found in the `dir` directory
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/generator/generator.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_generator_T = tag_RTM_generator_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_generator_T {
+    pub y: real_T,
+    pub w: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_generator_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn generator_initialize(
+    _generator_M: *mut RT_MODEL_generator_T,
+    generator_Y: *mut ExtY_generator_T,
+) {
+    (*generator_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn generator_step(
+    _generator_M: *mut RT_MODEL_generator_T,
+    generator_Y: *mut ExtY_generator_T,
+) {
+    (*generator_Y).y += 1.0;
+    (*generator_Y).w = [1.0, 2.0];
+}
+
+simulink_rs::import! {Generator, dir = "fixtures/generator", bindings = false}
+
+fn main() {
+    let mut generator = Generator::new();
+    generator.step();
+    assert_eq!(generator.y(), 1.0);
+}
@@ -0,0 +1,43 @@
+// Model header preferred over the other generated headers of the `dir` directory
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/discovery/discovery.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_discovery_T = tag_RTM_discovery_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_discovery_T {
+    pub y: real_T,
+    pub w: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_discovery_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn discovery_initialize(
+    _discovery_M: *mut RT_MODEL_discovery_T,
+    discovery_Y: *mut ExtY_discovery_T,
+) {
+    (*discovery_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn discovery_step(
+    _discovery_M: *mut RT_MODEL_discovery_T,
+    discovery_Y: *mut ExtY_discovery_T,
+) {
+    (*discovery_Y).y += 1.0;
+    (*discovery_Y).w = [1.0, 2.0];
+}
+
+simulink_rs::import! {Discovery, dir = "fixtures/discovery", bindings = false}
+
+fn main() {
+    let mut discovery = Discovery::new();
+    discovery.step();
+    assert_eq!(discovery.y(), 1.0);
+}
@@ -0,0 +1,67 @@
+// states with a pointer member, the wrapper is neither `Copy` nor `Clone`
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/dwork/dwork.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_dwork_T = tag_RTM_dwork_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_dwork_T {
+    pub Delay_DSTATE: real_T,
+    pub Buffer_PWORK: *mut real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_dwork_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_dwork_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_dwork_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_dwork_T,
+}
+
+// the model C source, the buffer is allocated by the initialization
+static mut BUFFER: [real_T; 1] = [0.0];
+#[no_mangle]
+pub unsafe extern "C" fn dwork_initialize(
+    dwork_M: *mut RT_MODEL_dwork_T,
+    _dwork_U: *mut ExtU_dwork_T,
+    dwork_Y: *mut ExtY_dwork_T,
+) {
+    let dw = &mut *(*dwork_M).dwork;
+    dw.Delay_DSTATE = 0.0;
+    dw.Buffer_PWORK = std::ptr::addr_of_mut!(BUFFER).cast();
+    *dw.Buffer_PWORK = 0.0;
+    (*dwork_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn dwork_step(
+    dwork_M: *mut RT_MODEL_dwork_T,
+    dwork_U: *mut ExtU_dwork_T,
+    dwork_Y: *mut ExtY_dwork_T,
+) {
+    let dw = &mut *(*dwork_M).dwork;
+    *dw.Buffer_PWORK += (*dwork_U).u;
+    (*dwork_Y).y = dw.Delay_DSTATE + *dw.Buffer_PWORK;
+    dw.Delay_DSTATE = (*dwork_U).u;
+}
+
+simulink_rs::import! {Dwork, "fixtures/dwork/dwork.h", bindings = false}
+
+fn main() {
+    let mut dwork = Dwork::new();
+    dwork.inputs.u = 1.0;
+    dwork.step();
+    dwork.step();
+    assert_eq!(dwork.outputs.y, 3.0);
+    dwork.reset();
+    assert_eq!(dwork.outputs.y, 0.0);
+}
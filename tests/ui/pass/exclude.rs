@@ -0,0 +1,43 @@
+// Model header left alone by the `exclude` suffixes
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/left/left.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_left_T = tag_RTM_left_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_left_T {
+    pub y: real_T,
+    pub w: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_left_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn left_initialize(
+    _left_M: *mut RT_MODEL_left_T,
+    left_Y: *mut ExtY_left_T,
+) {
+    (*left_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn left_step(
+    _left_M: *mut RT_MODEL_left_T,
+    left_Y: *mut ExtY_left_T,
+) {
+    (*left_Y).y += 1.0;
+    (*left_Y).w = [1.0, 2.0];
+}
+
+simulink_rs::import! {Left, dir = "fixtures/ambiguous", exclude = ["right.h", "_types.h"], bindings = false}
+
+fn main() {
+    let mut left = Left::new();
+    left.step();
+    assert_eq!(left.y(), 1.0);
+}
@@ -0,0 +1,108 @@
+// Closed loop step of a plant and a controller connected by their signal names
+#![allow(non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+// bindgen bindings of `fixtures/feedback/plant.h` and `fixtures/feedback/controller.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub const Mode_T_Hold: Mode_T = 0;
+pub const Mode_T_Track: Mode_T = 1;
+pub type Mode_T = ::std::os::raw::c_uint;
+pub type RT_MODEL_plant_T = tag_RTM_plant_T;
+pub type RT_MODEL_controller_T = tag_RTM_controller_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_plant_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_plant_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_plant_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_plant_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_plant_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_controller_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_controller_T {
+    pub u: real_T,
+    pub mode: Mode_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_controller_T {
+    pub errorStatus: *const char_T,
+}
+
+// the models C source
+#[no_mangle]
+pub unsafe extern "C" fn plant_initialize(
+    plant_M: *mut RT_MODEL_plant_T,
+    _plant_U: *mut ExtU_plant_T,
+    plant_Y: *mut ExtY_plant_T,
+) {
+    (*(*plant_M).dwork).Integrator_DSTATE = 1.0;
+    (*plant_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn plant_step(
+    plant_M: *mut RT_MODEL_plant_T,
+    plant_U: *mut ExtU_plant_T,
+    plant_Y: *mut ExtY_plant_T,
+) {
+    let dw = &mut *(*plant_M).dwork;
+    (*plant_Y).y = dw.Integrator_DSTATE;
+    dw.Integrator_DSTATE += (*plant_U).u;
+}
+#[no_mangle]
+pub unsafe extern "C" fn controller_initialize(
+    _controller_M: *mut RT_MODEL_controller_T,
+    _controller_U: *mut ExtU_controller_T,
+    controller_Y: *mut ExtY_controller_T,
+) {
+    (*controller_Y).u = 0.0;
+    (*controller_Y).mode = Mode_T_Hold;
+}
+#[no_mangle]
+pub unsafe extern "C" fn controller_step(
+    _controller_M: *mut RT_MODEL_controller_T,
+    controller_U: *mut ExtU_controller_T,
+    controller_Y: *mut ExtY_controller_T,
+) {
+    let y = (*controller_U).y;
+    (*controller_Y).u = -0.5 * y;
+    (*controller_Y).mode = if y != 0.0 { Mode_T_Track } else { Mode_T_Hold };
+}
+
+simulink_rs::import! {Plant, "fixtures/feedback/plant.h", bindings = false}
+simulink_rs::import! {Controller, "fixtures/feedback/controller.h", bindings = false}
+simulink_rs::feedback! {plant, controller, dir = "fixtures/feedback"}
+
+fn main() {
+    let (mut plant, mut controller) = (Plant::new(), Controller::new());
+    // the controller output `mode` has no plant input
+    let mut u = vec![];
+    for _ in 0..4 {
+        feedback_step(&mut plant, &mut controller);
+        assert_eq!(plant.inputs.u, controller.outputs.u);
+        u.push(controller.outputs.u);
+    }
+    assert_eq!(u, vec![0.0, -0.5, -0.5, -0.25]);
+    assert_eq!(controller.inputs.y, 0.5);
+    assert_eq!(controller.outputs.mode, Mode_T_Track);
+    assert_eq!(plant.outputs.y, 0.0);
+    assert_eq!(plant.states.Integrator_DSTATE, -0.25);
+}
@@ -0,0 +1,61 @@
+// Outputs only, without inputs and states structures
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/generator/generator.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_generator_T = tag_RTM_generator_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_generator_T {
+    pub y: real_T,
+    pub w: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_generator_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn generator_initialize(
+    _generator_M: *mut RT_MODEL_generator_T,
+    generator_Y: *mut ExtY_generator_T,
+) {
+    (*generator_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn generator_step(
+    _generator_M: *mut RT_MODEL_generator_T,
+    generator_Y: *mut ExtY_generator_T,
+) {
+    (*generator_Y).y += 1.0;
+    (*generator_Y).w = [1.0, 2.0];
+}
+
+simulink_rs::import! {Generator, "fixtures/generator/generator.h", bindings = false}
+
+fn run<M: simulink_rs::SimulinkModel>(model: &mut M, n: usize) {
+    model.reset();
+    (0..n).for_each(|_| model.step());
+}
+
+fn main() {
+    assert_eq!(Generator::SAMPLE_TIME, 0.5);
+    assert_eq!(Generator::INPUT_LEN, 0);
+    assert_eq!(Generator::metadata().version, Some("1.2"));
+    assert_eq!(Generator::metadata().codegen_date, None);
+    let mut generator = Generator::new();
+    generator.step();
+    generator.step();
+    assert_eq!(generator.y(), 2.0);
+    assert_eq!(generator.w(), &[1.0, 2.0]);
+    assert_eq!(generator.signals().count(), 3);
+    assert_eq!(generator.to_string(), "y: 2\nw: [1.0, 2.0]\n");
+    generator.reset();
+    assert_eq!(generator.outputs.y, 0.0);
+    run(&mut generator, 3);
+    assert_eq!(generator.outputs.y, 3.0);
+    assert_eq!(<Generator as simulink_rs::SimulinkModel>::OUTPUT_LEN, 3);
+}
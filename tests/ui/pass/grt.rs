@@ -0,0 +1,68 @@
+// GRT with global I/O, the entry points take no arguments
+#![allow(non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+// bindgen bindings of `fixtures/grt/grt.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_grt_T = tag_RTM_grt_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_grt_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_grt_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_grt_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_grt_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub static mut grt_DW: DW_grt_T = DW_grt_T {
+    Integrator_DSTATE: 0.0,
+};
+#[no_mangle]
+pub static mut grt_U: ExtU_grt_T = ExtU_grt_T { u: 0.0 };
+#[no_mangle]
+pub static mut grt_Y: ExtY_grt_T = ExtY_grt_T { y: 0.0 };
+#[no_mangle]
+pub unsafe extern "C" fn grt_initialize() {
+    grt_DW.Integrator_DSTATE = 0.0;
+    grt_Y.y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn grt_step() {
+    grt_Y.y = grt_DW.Integrator_DSTATE;
+    grt_DW.Integrator_DSTATE += grt_U.u;
+}
+#[no_mangle]
+pub unsafe extern "C" fn grt_terminate() {}
+
+simulink_rs::import! {Grt, "fixtures/grt/grt.h", bindings = false}
+
+fn main() {
+    let mut a = Grt::new();
+    let mut b = Grt::new();
+    a.inputs.u = 1.0;
+    b.inputs.u = 10.0;
+    for _ in 0..3 {
+        a.step();
+        b.step();
+    }
+    assert_eq!(a.outputs.y, 2.0);
+    assert_eq!(b.outputs.y, 20.0);
+    a.step();
+    assert_eq!(a.outputs.y, 3.0);
+    b.reset();
+    assert_eq!(b.outputs.y, 0.0);
+}
@@ -0,0 +1,83 @@
+// Shared stepping and observation of the controller with the `interior_mutability` flag
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/scalar/scalar.h`
+pub type real_T = f64;
+pub type real32_T = f32;
+pub type int32_T = i32;
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_scalar_T = tag_RTM_scalar_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_scalar_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_scalar_T {
+    pub u: real_T,
+    pub g: real32_T,
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_scalar_T {
+    pub y: real_T,
+    pub count: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_scalar_T {
+    pub errorStatus: *const char_T,
+    pub work: *mut DW_scalar_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn scalar_initialize(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    _scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    (*(*scalar_M).work).Integrator_DSTATE = 1.0;
+    (*scalar_Y).y = 0.0;
+    (*scalar_Y).count = 0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn scalar_step(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    let dw = &mut *(*scalar_M).work;
+    let u = &*scalar_U;
+    if u.enable != 0 {
+        dw.Integrator_DSTATE += u.u * u.g as f64;
+    }
+    (*scalar_Y).y = dw.Integrator_DSTATE;
+    (*scalar_Y).count += 1;
+}
+
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false, interior_mutability}
+
+fn main() {
+    let shared = std::rc::Rc::new(ScalarCell::default());
+    let observer = std::rc::Rc::clone(&shared);
+    shared.set_inputs(ExtU_scalar_T {
+        u: 2.0,
+        g: 0.5,
+        enable: 1,
+    });
+    assert_eq!(observer.inputs().u, 2.0);
+    // the default controller is initialized, the integrator starts at 1
+    shared.step();
+    assert_eq!(observer.outputs().y, 2.0);
+    shared.step();
+    assert_eq!(observer.outputs().y, 3.0);
+    assert_eq!(observer.outputs().count, 2);
+    drop(observer);
+    let scalar = std::rc::Rc::try_unwrap(shared).ok().unwrap().into_inner();
+    assert_eq!(scalar.outputs.count, 2);
+    assert_eq!(ScalarCell::new().outputs().count, 0);
+}
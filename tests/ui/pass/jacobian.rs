@@ -0,0 +1,87 @@
+// Finite-difference Jacobian of the outputs with respect to the floating point inputs
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/scalar/scalar.h`
+pub type real_T = f64;
+pub type real32_T = f32;
+pub type int32_T = i32;
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_scalar_T = tag_RTM_scalar_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_scalar_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_scalar_T {
+    pub u: real_T,
+    pub g: real32_T,
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_scalar_T {
+    pub y: real_T,
+    pub count: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_scalar_T {
+    pub errorStatus: *const char_T,
+    pub work: *mut DW_scalar_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn scalar_initialize(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    _scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    (*(*scalar_M).work).Integrator_DSTATE = 1.0;
+    (*scalar_Y).y = 0.0;
+    (*scalar_Y).count = 0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn scalar_step(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    let dw = &mut *(*scalar_M).work;
+    let u = &*scalar_U;
+    if u.enable != 0 {
+        dw.Integrator_DSTATE += u.u * u.g as f64;
+    }
+    (*scalar_Y).y = dw.Integrator_DSTATE;
+    (*scalar_Y).count += 1;
+}
+
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false}
+
+fn main() {
+    let mut scalar = Scalar::new();
+    scalar.inputs.u = 2.0;
+    scalar.inputs.g = 0.5;
+    scalar.inputs.enable = 1;
+    // the wrapper with a trace hook isn't `Copy` and has no Jacobian
+    #[cfg(not(feature = "trace"))]
+    {
+        // y = y0 + u * g: the columns are u and g, the boolean enable is left out
+        let jacobian = scalar.jacobian(1e-6);
+        assert_eq!(jacobian.len(), 2);
+        assert!(jacobian.iter().all(|row| row.len() == 2));
+        // the f32 input `g` is perturbed by `eps` rounded to f32
+        assert!((jacobian[0][0] - 0.5).abs() < 1e-9);
+        assert!((jacobian[0][1] - 2.0).abs() < 1e-9);
+        // the count increments at each step whatever the inputs
+        assert_eq!(jacobian[1], vec![0.0, 0.0]);
+        // the controller is left unchanged
+        assert_eq!(scalar.outputs.count, 0);
+        assert_eq!(scalar.states.Integrator_DSTATE, 1.0);
+    }
+    scalar.step();
+    assert_eq!(scalar.outputs.y, 2.0);
+}
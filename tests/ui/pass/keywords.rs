@@ -0,0 +1,68 @@
+// Rust keywords and digit prefixed variable names
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/keywords/keywords.h`, with the digit prefixed members
+// prefixed with `n_`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_keywords_T = tag_RTM_keywords_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_keywords_T {
+    pub match_: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_keywords_T {
+    pub type_: real_T,
+    pub n_2nd: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_keywords_T {
+    pub move_: real_T,
+    pub n_3rd: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_keywords_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_keywords_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn keywords_initialize(
+    keywords_M: *mut RT_MODEL_keywords_T,
+    _keywords_U: *mut ExtU_keywords_T,
+    keywords_Y: *mut ExtY_keywords_T,
+) {
+    (*(*keywords_M).dwork).match_ = 0.0;
+    (*keywords_Y).move_ = 0.0;
+    (*keywords_Y).n_3rd = [0.0; 2];
+}
+#[no_mangle]
+pub unsafe extern "C" fn keywords_step(
+    keywords_M: *mut RT_MODEL_keywords_T,
+    keywords_U: *mut ExtU_keywords_T,
+    keywords_Y: *mut ExtY_keywords_T,
+) {
+    let (dw, u, y) = (&mut *(*keywords_M).dwork, &*keywords_U, &mut *keywords_Y);
+    y.move_ = dw.match_;
+    y.n_3rd = [u.type_, u.n_2nd];
+    dw.match_ = u.type_ + u.n_2nd;
+}
+
+simulink_rs::import! {Keywords, "fixtures/keywords/keywords.h", bindings = false}
+
+fn main() {
+    let mut model = Keywords::new();
+    model.set_type(1.0);
+    model.set_n_2nd(2.0);
+    model.step();
+    model.step();
+    assert_eq!(model.r#move(), 3.0);
+    assert_eq!(model.n_3rd(), &[1.0, 2.0]);
+    assert_eq!(model.inputs_named(), vec![("type", 1.0), ("2nd", 2.0)]);
+    assert_eq!(model.outputs_named()[2], ("3rd[1]", 2.0));
+}
@@ -0,0 +1,43 @@
+// Model header selected by the model name among the headers of the `dir` directory
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/right/right.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_right_T = tag_RTM_right_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_right_T {
+    pub y: real_T,
+    pub w: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_right_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn right_initialize(
+    _right_M: *mut RT_MODEL_right_T,
+    right_Y: *mut ExtY_right_T,
+) {
+    (*right_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn right_step(
+    _right_M: *mut RT_MODEL_right_T,
+    right_Y: *mut ExtY_right_T,
+) {
+    (*right_Y).y += 1.0;
+    (*right_Y).w = [1.0, 2.0];
+}
+
+simulink_rs::import! {Right, dir = "fixtures/ambiguous", model = "right", bindings = false}
+
+fn main() {
+    let mut right = Right::new();
+    right.step();
+    assert_eq!(right.y(), 1.0);
+}
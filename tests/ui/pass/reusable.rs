@@ -0,0 +1,55 @@
+// Reusable function taking only the real-time model, pointing to the inputs, outputs and states
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/reusable/reusable.h`
+pub type real_T = f64;
+pub type RT_MODEL_reusable_T = tag_RTM_reusable_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_reusable_T {
+    pub UnitDelay_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_reusable_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_reusable_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_reusable_T {
+    pub inputs: *mut ExtU_reusable_T,
+    pub outputs: *mut ExtY_reusable_T,
+    pub states: *mut DW_reusable_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn reusable_initialize(reusable_M: *mut RT_MODEL_reusable_T) {
+    (*(*reusable_M).states).UnitDelay_DSTATE = 0.0;
+    (*(*reusable_M).outputs).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn reusable_step(reusable_M: *mut RT_MODEL_reusable_T) {
+    let dw = &mut *(*reusable_M).states;
+    (*(*reusable_M).outputs).y = dw.UnitDelay_DSTATE;
+    dw.UnitDelay_DSTATE = (*(*reusable_M).inputs).u;
+}
+
+simulink_rs::import! {Reusable, "fixtures/reusable/reusable.h", bindings = false}
+
+fn main() {
+    let mut reusable = Reusable::new();
+    reusable.inputs.u = 1.0;
+    reusable.step();
+    assert_eq!(reusable.outputs.y, 0.0);
+    reusable.inputs.u = 2.0;
+    reusable.step();
+    assert_eq!(reusable.outputs.y, 1.0);
+    reusable.step();
+    assert_eq!(reusable.outputs.y, 2.0);
+}
@@ -0,0 +1,155 @@
+// Scalar inputs, outputs and states
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/scalar/scalar.h`
+pub type real_T = f64;
+pub type real32_T = f32;
+pub type int32_T = i32;
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_scalar_T = tag_RTM_scalar_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_scalar_T {
+    pub Integrator_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_scalar_T {
+    pub u: real_T,
+    pub g: real32_T,
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_scalar_T {
+    pub y: real_T,
+    pub count: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_scalar_T {
+    pub errorStatus: *const char_T,
+    pub work: *mut DW_scalar_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn scalar_initialize(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    _scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    (*(*scalar_M).work).Integrator_DSTATE = 1.0;
+    (*scalar_Y).y = 0.0;
+    (*scalar_Y).count = 0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn scalar_step(
+    scalar_M: *mut RT_MODEL_scalar_T,
+    scalar_U: *mut ExtU_scalar_T,
+    scalar_Y: *mut ExtY_scalar_T,
+) {
+    let dw = &mut *(*scalar_M).work;
+    let u = &*scalar_U;
+    if u.enable != 0 {
+        dw.Integrator_DSTATE += u.u * u.g as f64;
+    }
+    (*scalar_Y).y = dw.Integrator_DSTATE;
+    (*scalar_Y).count += 1;
+}
+
+simulink_rs::import! {Scalar, "fixtures/scalar/scalar.h", bindings = false, precision = f64}
+
+fn main() {
+    assert_eq!(Scalar::SAMPLE_TIME, 0.01);
+    assert_eq!(Scalar::MODEL_VERSION, "1.3");
+    assert_eq!(Scalar::CODEGEN_DATE, "Mon Mar  6 14:22:31 2023");
+    assert_eq!(Scalar::CHECKSUM, [0x1A2B3C4D, 0, 0xDEADBEEF, 0x12345678]);
+    assert!(Scalar::checksum_matches([439041101, 0, 3735928559, 305419896]));
+    assert!(!Scalar::checksum_matches([0; 4]));
+    assert_eq!(
+        Scalar::metadata(),
+        simulink_rs::ModelMetadata {
+            name: "scalar",
+            version: Some("1.3"),
+            coder_version: Some("9.4 (R2020b) 29-Jul-2020"),
+            codegen_date: Some("Mon Mar  6 14:22:31 2023"),
+            solver: Some("FixedStepDiscrete"),
+        }
+    );
+    let mut scalar = Scalar::new();
+    scalar.inputs.u = 2.0;
+    scalar.inputs.g = 0.5;
+    scalar.step();
+    assert_eq!(scalar.outputs.y, 1.0);
+    scalar.inputs.enable = 1;
+    scalar.step_for(3);
+    assert_eq!(scalar.outputs.y, 4.0);
+    assert_eq!(scalar.outputs.count, 4);
+    assert_eq!(Vec::from(&scalar.outputs), vec![4.0, 4.0]);
+    assert_eq!(scalar.outputs, ExtY_scalar_T { y: 4.0, count: 4 });
+    assert_ne!(scalar.inputs, ExtU_scalar_T::default());
+    let snapshot = scalar.snapshot();
+    scalar.step_for(2);
+    let outputs = scalar.outputs;
+    scalar.inputs.u = 0.0;
+    scalar.restore(&snapshot);
+    assert_eq!(scalar.outputs.y, 4.0);
+    scalar.step_for(2);
+    assert_eq!(scalar.outputs, outputs);
+    scalar.restore(&snapshot);
+    scalar.step_const::<2>();
+    assert_eq!(scalar.outputs, outputs);
+    let mut rtm = scalar.rtm_raw();
+    let (inputs, outputs) = (scalar.inputs_raw(), scalar.outputs_raw());
+    unsafe { scalar_step(&mut rtm, inputs, outputs) };
+    assert_eq!(scalar.outputs.count, 7);
+    scalar.reset();
+    assert_eq!(scalar.outputs.count, 0);
+    #[cfg(feature = "trace")]
+    {
+        use std::sync::{Arc, Mutex};
+        let trace = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&trace);
+        scalar.set_trace_hook(Box::new(move |u: &ExtU_scalar_T, y: &ExtY_scalar_T| {
+            log.lock().unwrap().push((u.u, y.y, y.count))
+        }));
+        scalar.inputs = ExtU_scalar_T {
+            u: 1.0,
+            g: 0.5,
+            enable: 1,
+        };
+        scalar.step();
+        scalar.step_for(2);
+        assert_eq!(
+            *trace.lock().unwrap(),
+            vec![(1.0, 1.5, 1), (1.0, 2.0, 2), (1.0, 2.5, 3)]
+        );
+        scalar.clear_trace_hook();
+        scalar.step();
+        assert_eq!(trace.lock().unwrap().len(), 3);
+    }
+    let inputs = ExtU_scalar_T::builder().u(2.0).g(0.5).enable(1).build();
+    assert_eq!(inputs, ScalarInputsBuilder::default().u(2.0).g(0.5).enable(1).build());
+    let mut scalar = Scalar::with_inputs(inputs);
+    scalar.step();
+    assert_eq!(scalar.outputs.y, 2.0);
+    let inputs = [ExtU_scalar_T::builder().u(1.0).g(1.0).enable(1).build(); 3];
+    let mut explicit = Scalar::new();
+    let mut scalar = Scalar::new();
+    for inputs in inputs {
+        explicit.inputs = inputs;
+        explicit.step();
+        assert_eq!(scalar.step_with(inputs), explicit.outputs);
+    }
+    assert_eq!(scalar.outputs, ExtY_scalar_T { y: 4.0, count: 3 });
+    let mut expected = [ExtY_scalar_T::default(); 3];
+    Scalar::new().rollout(&inputs, &mut expected);
+    assert_eq!(Scalar::verify_trace(&inputs, &expected), Ok(()));
+    expected[1].count = 0;
+    expected[2].y = 0.0;
+    let mismatch = Scalar::verify_trace(&inputs, &expected).unwrap_err();
+    assert_eq!(mismatch, ScalarTraceMismatch { tick: 1, field: "count" });
+    assert_eq!(mismatch.to_string(), "output `count` diverged at tick 1");
+}
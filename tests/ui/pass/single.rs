@@ -0,0 +1,57 @@
+// Single precision `real_T`, detected from `#define real_T float` in `rtwtypes.h`
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/single/single.h`
+pub type boolean_T = u8;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_single_T = tag_RTM_single_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_single_T {
+    pub u: [f32; 2usize],
+    pub enable: boolean_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_single_T {
+    pub y: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_single_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn single_initialize(
+    _single_M: *mut RT_MODEL_single_T,
+    _single_U: *mut ExtU_single_T,
+    single_Y: *mut ExtY_single_T,
+) {
+    (*single_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn single_step(
+    _single_M: *mut RT_MODEL_single_T,
+    single_U: *mut ExtU_single_T,
+    single_Y: *mut ExtY_single_T,
+) {
+    let u = &*single_U;
+    if u.enable != 0 {
+        (*single_Y).y = u.u[0] * u.u[1];
+    }
+}
+
+simulink_rs::import! {Single, "fixtures/single/single.h", bindings = false}
+
+fn main() {
+    let mut single = Single::new();
+    single.set_inputs(&[1.5f32, 2.0, 1.0]).unwrap();
+    assert_eq!(single.inputs.enable, 1);
+    single.step();
+    assert_eq!(single.y(), 3.0f32);
+    let flat: Vec<f32> = Vec::from(&single.inputs);
+    assert_eq!(flat, vec![1.5, 2.0, 1.0]);
+    assert_eq!(Vec::<f32>::from(&single.outputs), vec![3.0]);
+}
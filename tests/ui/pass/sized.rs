@@ -0,0 +1,66 @@
+// Array sizes of integer arithmetic on the `#define` macros of the header
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/sized/sized.h`
+pub type real_T = f64;
+pub type int32_T = i32;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_sized_T = tag_RTM_sized_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_sized_T {
+    pub Delay_DSTATE: [real_T; 4usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_sized_T {
+    pub buf: [real_T; 7usize],
+    pub window: [[int32_T; 4usize]; 3usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_sized_T {
+    pub y: [real_T; 4usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_sized_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_sized_T,
+}
+
+// the model C source, the sums of the buffer halves, of the window and of the delayed sums
+#[no_mangle]
+pub unsafe extern "C" fn sized_initialize(
+    _sized_M: *mut RT_MODEL_sized_T,
+    _sized_U: *mut ExtU_sized_T,
+    _sized_Y: *mut ExtY_sized_T,
+) {
+}
+#[no_mangle]
+pub unsafe extern "C" fn sized_step(
+    sized_M: *mut RT_MODEL_sized_T,
+    sized_U: *mut ExtU_sized_T,
+    sized_Y: *mut ExtY_sized_T,
+) {
+    let (dw, u, y) = (&mut *(*sized_M).dwork, &*sized_U, &mut *sized_Y);
+    let (head, tail) = u.buf.split_at(3);
+    let window: i32 = u.window.iter().flatten().sum();
+    y.y = [head.iter().sum(), tail.iter().sum(), window as f64, dw.Delay_DSTATE[0]];
+    dw.Delay_DSTATE.rotate_left(1);
+    dw.Delay_DSTATE[3] = y.y[0];
+}
+
+simulink_rs::import! {Sized, "fixtures/sized/sized.h", bindings = false}
+
+fn main() {
+    assert_eq!(Sized::INPUT_LEN, 7 + 12);
+    assert_eq!(Sized::OUTPUT_LEN, 4);
+    assert_eq!(Sized::INPUT_LAYOUT, &[("buf", 7), ("window", 12)]);
+    let mut sized = Sized::new();
+    let inputs: Vec<f64> = (1..=7).chain([1; 12]).map(f64::from).collect();
+    sized.set_inputs(&inputs).unwrap();
+    assert_eq!(sized.inputs.window, [[1; 4]; 3]);
+    sized.step_for(5);
+    assert_eq!(sized.outputs.y, [6.0, 22.0, 12.0, 6.0]);
+}
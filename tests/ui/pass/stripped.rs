@@ -0,0 +1,44 @@
+// Model name inferred from the structure tags of a header without the `File:` line
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/stripped/stripped.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_stripped_T = tag_RTM_stripped_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_stripped_T {
+    pub y: real_T,
+    pub w: [real_T; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_stripped_T {
+    pub errorStatus: *const char_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn stripped_initialize(
+    _stripped_M: *mut RT_MODEL_stripped_T,
+    stripped_Y: *mut ExtY_stripped_T,
+) {
+    (*stripped_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn stripped_step(
+    _stripped_M: *mut RT_MODEL_stripped_T,
+    stripped_Y: *mut ExtY_stripped_T,
+) {
+    (*stripped_Y).y += 1.0;
+    (*stripped_Y).w = [1.0, 2.0];
+}
+
+simulink_rs::import! {Stripped, "fixtures/stripped/stripped.h", bindings = false}
+
+fn main() {
+    assert_eq!(Stripped::metadata().name, "stripped");
+    let mut stripped = Stripped::new();
+    stripped.step();
+    assert_eq!(stripped.y(), 1.0);
+}
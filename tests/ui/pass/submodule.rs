@@ -0,0 +1,85 @@
+// Models imported in submodules from the bindings written once at the crate root
+#![allow(non_camel_case_types, non_snake_case)]
+
+mod ffi {
+    // bindgen bindings of `fixtures/generator/generator.h`
+    pub type real_T = f64;
+    pub type char_T = ::std::os::raw::c_char;
+    pub type RT_MODEL_generator_T = tag_RTM_generator_T;
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ExtY_generator_T {
+        pub y: real_T,
+        pub w: [real_T; 2usize],
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct tag_RTM_generator_T {
+        pub errorStatus: *const char_T,
+    }
+
+    // the model C source
+    #[no_mangle]
+    pub unsafe extern "C" fn generator_initialize(
+        _generator_M: *mut RT_MODEL_generator_T,
+        generator_Y: *mut ExtY_generator_T,
+    ) {
+        (*generator_Y).y = 0.0;
+    }
+    #[no_mangle]
+    pub unsafe extern "C" fn generator_step(
+        _generator_M: *mut RT_MODEL_generator_T,
+        generator_Y: *mut ExtY_generator_T,
+    ) {
+        (*generator_Y).y += 1.0;
+        (*generator_Y).w = [1.0, 2.0];
+    }
+
+    // bindgen bindings of `fixtures/discovery/discovery.h`
+    pub type RT_MODEL_discovery_T = tag_RTM_discovery_T;
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct ExtY_discovery_T {
+        pub y: real_T,
+        pub w: [real_T; 2usize],
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct tag_RTM_discovery_T {
+        pub errorStatus: *const char_T,
+    }
+
+    // the model C source
+    #[no_mangle]
+    pub unsafe extern "C" fn discovery_initialize(
+        _discovery_M: *mut RT_MODEL_discovery_T,
+        discovery_Y: *mut ExtY_discovery_T,
+    ) {
+        (*discovery_Y).y = 0.0;
+    }
+    #[no_mangle]
+    pub unsafe extern "C" fn discovery_step(
+        _discovery_M: *mut RT_MODEL_discovery_T,
+        discovery_Y: *mut ExtY_discovery_T,
+    ) {
+        (*discovery_Y).y += 1.0;
+        (*discovery_Y).w = [1.0, 2.0];
+    }
+}
+
+mod generator {
+    simulink_rs::import! {Generator, "fixtures/generator/generator.h", bindings = crate::ffi}
+}
+mod discovery {
+    simulink_rs::import! {Discovery, "fixtures/discovery/discovery.h", bindings = super::ffi}
+}
+
+fn main() {
+    let mut generator = generator::Generator::new();
+    generator.step();
+    assert_eq!(generator.y(), 1.0);
+    let mut discovery = discovery::Discovery::new();
+    discovery.step_for(2);
+    assert_eq!(discovery.y(), 2.0);
+    assert_eq!(discovery.outputs, ffi::ExtY_discovery_T { y: 2.0, w: [1.0, 2.0] });
+}
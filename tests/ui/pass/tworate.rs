@@ -0,0 +1,89 @@
+// Two-rate model driven from its tasks sample times
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/tworate/tworate.h`
+pub type real_T = f64;
+pub type int32_T = i32;
+pub type char_T = ::std::os::raw::c_char;
+pub type RT_MODEL_tworate_T = tag_RTM_tworate_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_tworate_T {
+    pub Slow_DSTATE: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_tworate_T {
+    pub u: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_tworate_T {
+    pub y: real_T,
+    pub fast: int32_T,
+    pub slow: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_tworate_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_tworate_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn tworate_initialize(
+    tworate_M: *mut RT_MODEL_tworate_T,
+    _tworate_U: *mut ExtU_tworate_T,
+    tworate_Y: *mut ExtY_tworate_T,
+) {
+    (*(*tworate_M).dwork).Slow_DSTATE = 0;
+    *tworate_Y = ExtY_tworate_T {
+        y: 0.0,
+        fast: 0,
+        slow: 0,
+    };
+}
+#[no_mangle]
+pub unsafe extern "C" fn tworate_step0(
+    tworate_M: *mut RT_MODEL_tworate_T,
+    tworate_U: *mut ExtU_tworate_T,
+    tworate_Y: *mut ExtY_tworate_T,
+) {
+    let y = &mut *tworate_Y;
+    y.y = (*tworate_U).u;
+    y.fast += 1;
+    y.slow = (*(*tworate_M).dwork).Slow_DSTATE;
+}
+#[no_mangle]
+pub unsafe extern "C" fn tworate_step1(tworate_M: *mut RT_MODEL_tworate_T) {
+    (*(*tworate_M).dwork).Slow_DSTATE += 1;
+}
+
+simulink_rs::import! {TwoRate, "fixtures/tworate/tworate.h", bindings = false, precision = f64}
+
+fn main() {
+    assert_eq!(TwoRate::sample_time(), Some(0.01));
+    assert_eq!(TwoRate::TASK_PERIODS, &[0.01, 0.1]);
+    assert_eq!(TwoRate::TASK_OFFSETS, &[0.0, 0.05]);
+    let mut model = TwoRate::new();
+    model.inputs.u = 3.0;
+    // the slow task is due at 0.05s, 0.15s and 0.25s
+    for k in 0..=25 {
+        model.step_due(k as f64 * TwoRate::SAMPLE_TIME);
+    }
+    assert_eq!(model.outputs.fast, 26);
+    assert_eq!(model.outputs.slow, 2);
+    assert_eq!(model.outputs.y, 3.0);
+    model.step(0).unwrap();
+    assert_eq!(model.outputs.slow, 3);
+    assert_eq!(
+        model.step(2),
+        Err(simulink_rs::SimulinkError::IndexOutOfBounds {
+            field: "task_id",
+            index: 2,
+            len: 2
+        })
+    );
+    assert_eq!(model.outputs.slow, 3);
+}
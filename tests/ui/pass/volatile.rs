@@ -0,0 +1,65 @@
+// `const` and `volatile` qualified members and types
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/volatile/volatile.h`
+pub type real_T = f64;
+pub type char_T = ::std::os::raw::c_char;
+pub type reg16_T = ::std::os::raw::c_ushort;
+pub type RT_MODEL_volatile_T = tag_RTM_volatile_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_volatile_T {
+    pub Delay_DSTATE: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_volatile_T {
+    pub u: real_T,
+    pub v: [real_T; 2usize],
+    pub status: reg16_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_volatile_T {
+    pub y: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_volatile_T {
+    pub errorStatus: *const char_T,
+    pub dwork: *mut DW_volatile_T,
+}
+
+// the model C source
+#[no_mangle]
+pub unsafe extern "C" fn volatile_initialize(
+    volatile_M: *mut RT_MODEL_volatile_T,
+    _volatile_U: *mut ExtU_volatile_T,
+    volatile_Y: *mut ExtY_volatile_T,
+) {
+    (*(*volatile_M).dwork).Delay_DSTATE = 0.0;
+    (*volatile_Y).y = 0.0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn volatile_step(
+    volatile_M: *mut RT_MODEL_volatile_T,
+    volatile_U: *mut ExtU_volatile_T,
+    volatile_Y: *mut ExtY_volatile_T,
+) {
+    let (dw, u) = (&mut *(*volatile_M).dwork, &*volatile_U);
+    (*volatile_Y).y = dw.Delay_DSTATE;
+    dw.Delay_DSTATE = u.u + u.v[0] + u.v[1] + u.status as f64;
+}
+
+simulink_rs::import! {Volatile, "fixtures/volatile/volatile.h", bindings = false}
+
+fn main() {
+    assert_eq!(Volatile::INPUT_LEN, 4);
+    assert_eq!(ExtU_volatile_T::default().status, 0u16);
+    let mut model = Volatile::new();
+    model.set_inputs(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+    model.step();
+    model.step();
+    assert_eq!(model.y(), 10.0);
+    assert_eq!(model.inputs_named()[1], ("v[0]", 2.0));
+}
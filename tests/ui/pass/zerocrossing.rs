@@ -0,0 +1,91 @@
+// Previous zero-crossings of a triggered subsystem, wired into the real-time model
+#![allow(non_camel_case_types, non_snake_case)]
+
+// bindgen bindings of `fixtures/zerocrossing/zerocrossing.h`
+pub type real_T = f64;
+pub type int32_T = i32;
+pub type char_T = ::std::os::raw::c_char;
+pub type ZCSigState = u8;
+pub type RT_MODEL_zerocrossing_T = tag_RTM_zerocrossing_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DW_zerocrossing_T {
+    pub Counter_DSTATE: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct PrevZCX_zerocrossing_T {
+    pub Counter_Trig_ZCE: ZCSigState,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtU_zerocrossing_T {
+    pub trigger: real_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtY_zerocrossing_T {
+    pub edges: int32_T,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tag_RTM_zerocrossing_T {
+    pub errorStatus: *const char_T,
+    pub prevZCSigState: *mut PrevZCX_zerocrossing_T,
+    pub dwork: *mut DW_zerocrossing_T,
+}
+
+// the model C source, counting the rising edges of the trigger
+const POS_ZCSIG: ZCSigState = 1;
+const UNINITIALIZED_ZCSIG: ZCSigState = 3;
+#[no_mangle]
+pub unsafe extern "C" fn zerocrossing_initialize(
+    zerocrossing_M: *mut RT_MODEL_zerocrossing_T,
+    _zerocrossing_U: *mut ExtU_zerocrossing_T,
+    zerocrossing_Y: *mut ExtY_zerocrossing_T,
+) {
+    (*(*zerocrossing_M).prevZCSigState).Counter_Trig_ZCE = UNINITIALIZED_ZCSIG;
+    (*(*zerocrossing_M).dwork).Counter_DSTATE = 0;
+    (*zerocrossing_Y).edges = 0;
+}
+#[no_mangle]
+pub unsafe extern "C" fn zerocrossing_step(
+    zerocrossing_M: *mut RT_MODEL_zerocrossing_T,
+    zerocrossing_U: *mut ExtU_zerocrossing_T,
+    zerocrossing_Y: *mut ExtY_zerocrossing_T,
+) {
+    let zc = &mut *(*zerocrossing_M).prevZCSigState;
+    let dw = &mut *(*zerocrossing_M).dwork;
+    let positive = (*zerocrossing_U).trigger > 0.0;
+    if positive && zc.Counter_Trig_ZCE != POS_ZCSIG && zc.Counter_Trig_ZCE != UNINITIALIZED_ZCSIG {
+        dw.Counter_DSTATE += 1;
+    }
+    zc.Counter_Trig_ZCE = positive as ZCSigState;
+    (*zerocrossing_Y).edges = dw.Counter_DSTATE;
+}
+
+simulink_rs::import! {Edges, "fixtures/zerocrossing/zerocrossing.h", bindings = false, expose_states}
+
+fn main() {
+    let mut edges = Edges::new();
+    assert_eq!(edges.zero_crossings.Counter_Trig_ZCE, UNINITIALIZED_ZCSIG);
+    for trigger in [1.0, 0.0, 1.0, 1.0, 0.0, 1.0] {
+        edges.inputs.trigger = trigger;
+        edges.step();
+    }
+    assert_eq!(edges.outputs.edges, 2);
+    assert_eq!(edges.zero_crossings.Counter_Trig_ZCE, POS_ZCSIG);
+    let snapshot = edges.snapshot();
+    edges.inputs.trigger = 0.0;
+    edges.step();
+    edges.inputs.trigger = 1.0;
+    edges.step();
+    assert_eq!(edges.outputs.edges, 3);
+    edges.restore(&snapshot);
+    edges.step();
+    assert_eq!(edges.outputs.edges, 2);
+    edges.reset();
+    edges.step();
+    assert_eq!(edges.outputs.edges, 0);
+    assert_eq!(PrevZCX_zerocrossing_T::default().Counter_Trig_ZCE, 0);
+}